@@ -0,0 +1,347 @@
+// Copyright 2013-2014 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Axis-aligned bounding boxes.
+
+use structure::*;
+
+use approx;
+use matrix::{Matrix3, Matrix4};
+use num::{BaseFloat, BaseNum};
+use point::{Point2, Point3};
+use transform::{Transform2, Transform3};
+use vector::{Vector2, Vector3};
+
+#[cfg(feature = "mint")]
+use mint;
+
+/// An axis-aligned bounding box in 2-dimensional space.
+///
+/// This type is marked as `#[repr(C)]`.
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Aabb2<S> {
+    /// The corner with the smallest coordinates.
+    pub min: Point2<S>,
+    /// The corner with the largest coordinates.
+    pub max: Point2<S>,
+}
+
+/// An axis-aligned bounding box in 3-dimensional space.
+///
+/// This type is marked as `#[repr(C)]`.
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Aabb3<S> {
+    /// The corner with the smallest coordinates.
+    pub min: Point3<S>,
+    /// The corner with the largest coordinates.
+    pub max: Point3<S>,
+}
+
+#[inline]
+pub(crate) fn partial_min<S: PartialOrd>(a: S, b: S) -> S {
+    if b < a {
+        b
+    } else {
+        a
+    }
+}
+
+#[inline]
+pub(crate) fn partial_max<S: PartialOrd>(a: S, b: S) -> S {
+    if b > a {
+        b
+    } else {
+        a
+    }
+}
+
+macro_rules! impl_aabb {
+    ($AabbN:ident, $PointN:ident, $VectorN:ident { $($field:ident),+ }) => {
+        impl<S: BaseNum> $AabbN<S> {
+            /// Construct a new bounding box from two opposite corners. The
+            /// corners do not need to be ordered.
+            #[inline]
+            pub fn new(p1: $PointN<S>, p2: $PointN<S>) -> $AabbN<S> {
+                $AabbN {
+                    min: p1.zip(p2, partial_min),
+                    max: p1.zip(p2, partial_max),
+                }
+            }
+
+            /// Construct the smallest bounding box containing all of the
+            /// points. Returns `None` if the iterator is empty.
+            pub fn from_points<I>(points: I) -> Option<$AabbN<S>>
+            where
+                I: IntoIterator<Item = $PointN<S>>,
+            {
+                let mut points = points.into_iter();
+                let first = points.next()?;
+
+                Some(points.fold($AabbN::new(first, first), |aabb, p| aabb.grow(p)))
+            }
+
+            /// Return the smallest bounding box containing both this box and
+            /// `point`.
+            #[inline]
+            pub fn grow(self, point: $PointN<S>) -> $AabbN<S> {
+                $AabbN {
+                    min: self.min.zip(point, partial_min),
+                    max: self.max.zip(point, partial_max),
+                }
+            }
+
+            /// Return the smallest bounding box containing both this box and
+            /// `other`.
+            #[inline]
+            pub fn union(self, other: $AabbN<S>) -> $AabbN<S> {
+                $AabbN {
+                    min: self.min.zip(other.min, partial_min),
+                    max: self.max.zip(other.max, partial_max),
+                }
+            }
+
+            /// Return the region shared by this box and `other`, or `None` if
+            /// they do not overlap. Boxes that only touch produce a box with
+            /// zero extent along the touching axis.
+            pub fn intersection(self, other: $AabbN<S>) -> Option<$AabbN<S>> {
+                if self.intersects(other) {
+                    Some($AabbN {
+                        min: self.min.zip(other.min, partial_max),
+                        max: self.max.zip(other.max, partial_min),
+                    })
+                } else {
+                    None
+                }
+            }
+
+            /// Test if this box overlaps `other`. Touching boxes count as
+            /// overlapping.
+            #[inline]
+            pub fn intersects(self, other: $AabbN<S>) -> bool {
+                $(self.min.$field <= other.max.$field && other.min.$field <= self.max.$field)&&+
+            }
+
+            /// Test if `point` lies inside or on the boundary of this box.
+            #[inline]
+            pub fn contains(self, point: $PointN<S>) -> bool {
+                $(self.min.$field <= point.$field && point.$field <= self.max.$field)&&+
+            }
+
+            /// Test if `other` lies entirely inside this box.
+            #[inline]
+            pub fn contains_aabb(self, other: $AabbN<S>) -> bool {
+                self.contains(other.min) && self.contains(other.max)
+            }
+
+            /// The size of the box along each axis.
+            #[inline]
+            pub fn extents(self) -> $VectorN<S> {
+                self.max - self.min
+            }
+
+            /// Half the size of the box along each axis.
+            #[inline]
+            pub fn half_extents(self) -> $VectorN<S> {
+                self.extents() / (S::one() + S::one())
+            }
+
+            /// The point in the middle of the box.
+            #[inline]
+            pub fn center(self) -> $PointN<S> {
+                self.min.midpoint(self.max)
+            }
+        }
+
+        impl<S: BaseFloat> approx::AbsDiffEq for $AabbN<S> {
+            type Epsilon = S::Epsilon;
+
+            #[inline]
+            fn default_epsilon() -> S::Epsilon {
+                S::default_epsilon()
+            }
+
+            #[inline]
+            fn abs_diff_eq(&self, other: &Self, epsilon: S::Epsilon) -> bool {
+                $PointN::abs_diff_eq(&self.min, &other.min, epsilon)
+                    && $PointN::abs_diff_eq(&self.max, &other.max, epsilon)
+            }
+        }
+
+        impl<S: BaseFloat> approx::RelativeEq for $AabbN<S> {
+            #[inline]
+            fn default_max_relative() -> S::Epsilon {
+                S::default_max_relative()
+            }
+
+            #[inline]
+            fn relative_eq(&self, other: &Self, epsilon: S::Epsilon, max_relative: S::Epsilon) -> bool {
+                $PointN::relative_eq(&self.min, &other.min, epsilon, max_relative)
+                    && $PointN::relative_eq(&self.max, &other.max, epsilon, max_relative)
+            }
+        }
+
+        impl<S: BaseFloat> approx::UlpsEq for $AabbN<S> {
+            #[inline]
+            fn default_max_ulps() -> u32 {
+                S::default_max_ulps()
+            }
+
+            #[inline]
+            fn ulps_eq(&self, other: &Self, epsilon: S::Epsilon, max_ulps: u32) -> bool {
+                $PointN::ulps_eq(&self.min, &other.min, epsilon, max_ulps)
+                    && $PointN::ulps_eq(&self.max, &other.max, epsilon, max_ulps)
+            }
+        }
+
+        #[cfg(feature = "mint")]
+        impl<S: Clone> From<$AabbN<S>> for [mint::$PointN<S>; 2] {
+            #[inline]
+            fn from(aabb: $AabbN<S>) -> Self {
+                [aabb.min.into(), aabb.max.into()]
+            }
+        }
+
+        #[cfg(feature = "mint")]
+        impl<S> From<[mint::$PointN<S>; 2]> for $AabbN<S> {
+            #[inline]
+            fn from(corners: [mint::$PointN<S>; 2]) -> Self {
+                let [min, max] = corners;
+                $AabbN {
+                    min: min.into(),
+                    max: max.into(),
+                }
+            }
+        }
+
+        #[cfg(feature = "bytemuck")]
+        impl_bytemuck_cast!($AabbN);
+    };
+}
+
+impl_aabb!(Aabb2, Point2, Vector2 { x, y });
+impl_aabb!(Aabb3, Point3, Vector3 { x, y, z });
+
+impl<S: BaseNum> Aabb2<S> {
+    /// The area enclosed by the box.
+    #[inline]
+    pub fn area(self) -> S {
+        self.extents().product()
+    }
+
+    /// The length of the boundary of the box.
+    #[inline]
+    pub fn perimeter(self) -> S {
+        let e = self.extents();
+        let two = S::one() + S::one();
+        two * (e.x + e.y)
+    }
+
+    /// The four corners of the box, in counter-clockwise order starting
+    /// from `min`.
+    pub fn corners(self) -> [Point2<S>; 4] {
+        let (min, max) = (self.min, self.max);
+        [
+            min,
+            Point2::new(max.x, min.y),
+            max,
+            Point2::new(min.x, max.y),
+        ]
+    }
+}
+
+impl<S: BaseFloat> Aabb2<S> {
+    /// Compute the bounding box of this box after it has been transformed.
+    ///
+    /// The transform is assumed to be affine. The resulting box encloses the
+    /// transformed corners tightly, and is computed using Arvo's method
+    /// (_Graphics Gems_, 1990) without transforming each corner individually.
+    pub fn transform<T: Transform2<Scalar = S>>(self, transform: T) -> Aabb2<S> {
+        let m: Matrix3<S> = transform.into();
+        let mut min = Point2::new(m.z.x, m.z.y);
+        let mut max = min;
+
+        for i in 0..2 {
+            for j in 0..2 {
+                let a = m[j][i] * self.min[j];
+                let b = m[j][i] * self.max[j];
+                min[i] += partial_min(a, b);
+                max[i] += partial_max(a, b);
+            }
+        }
+
+        Aabb2 { min, max }
+    }
+}
+
+impl<S: BaseNum> Aabb3<S> {
+    /// The total area of the six faces of the box.
+    #[inline]
+    pub fn surface_area(self) -> S {
+        let e = self.extents();
+        let two = S::one() + S::one();
+        two * (e.x * e.y + e.y * e.z + e.z * e.x)
+    }
+
+    /// The volume enclosed by the box.
+    #[inline]
+    pub fn volume(self) -> S {
+        self.extents().product()
+    }
+
+    /// The eight corners of the box. The index of each corner encodes which
+    /// of `min` or `max` is used for each axis, with bit 0 for `x`, bit 1
+    /// for `y` and bit 2 for `z`.
+    pub fn corners(self) -> [Point3<S>; 8] {
+        let (min, max) = (self.min, self.max);
+        [
+            min,
+            Point3::new(max.x, min.y, min.z),
+            Point3::new(min.x, max.y, min.z),
+            Point3::new(max.x, max.y, min.z),
+            Point3::new(min.x, min.y, max.z),
+            Point3::new(max.x, min.y, max.z),
+            Point3::new(min.x, max.y, max.z),
+            max,
+        ]
+    }
+}
+
+impl<S: BaseFloat> Aabb3<S> {
+    /// Compute the bounding box of this box after it has been transformed.
+    ///
+    /// The transform is assumed to be affine. The resulting box encloses the
+    /// transformed corners tightly, and is computed using Arvo's method
+    /// (_Graphics Gems_, 1990) without transforming each corner individually.
+    pub fn transform<T: Transform3<Scalar = S>>(self, transform: T) -> Aabb3<S> {
+        let m: Matrix4<S> = transform.into();
+        let mut min = Point3::from_vec(m.w.truncate());
+        let mut max = min;
+
+        for i in 0..3 {
+            for j in 0..3 {
+                let a = m[j][i] * self.min[j];
+                let b = m[j][i] * self.max[j];
+                min[i] += partial_min(a, b);
+                max[i] += partial_max(a, b);
+            }
+        }
+
+        Aabb3 { min, max }
+    }
+}
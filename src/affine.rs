@@ -0,0 +1,478 @@
+// Copyright 2013-2014 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Affine transformations stored without the constant bottom row of their
+//! homogeneous matrices.
+
+use std::ops::*;
+
+use structure::*;
+
+use angle::Rad;
+use approx;
+use matrix::{Matrix2, Matrix3, Matrix4};
+use num::BaseFloat;
+use point::{Point2, Point3};
+use quaternion::Quaternion;
+use rotation::{Basis2, Rotation2, Rotation3};
+use transform::{Decomposed, Transform, Transform2, Transform3};
+use vector::{Vector2, Vector3, Vector4};
+
+#[cfg(feature = "mint")]
+use mint;
+
+/// A two-dimensional affine transformation, stored as the top two rows of a
+/// 3x3 homogeneous matrix.
+///
+/// Unlike the other matrix types, the fields are the _rows_ of the matrix:
+/// `x` computes the transformed _x_ coordinate and `y` the _y_ coordinate,
+/// with the translation in the last element of each row.
+///
+/// This type is marked as `#[repr(C)]`.
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Affine2<S> {
+    /// The first row of the matrix.
+    pub x: Vector3<S>,
+    /// The second row of the matrix.
+    pub y: Vector3<S>,
+}
+
+/// A three-dimensional affine transformation, stored as the top three rows
+/// of a 4x4 homogeneous matrix.
+///
+/// Unlike the other matrix types, the fields are the _rows_ of the matrix:
+/// `x`, `y` and `z` compute the corresponding transformed coordinates, with
+/// the translation in the last element of each row. Each row is a
+/// `Vector4`, so the layout needs no padding when uploaded to a GPU as
+/// three `vec4`s, and matches row-major 3x4 formats such as the ones used
+/// for ray tracing instance transforms.
+///
+/// This type is marked as `#[repr(C)]`.
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Affine3<S> {
+    /// The first row of the matrix.
+    pub x: Vector4<S>,
+    /// The second row of the matrix.
+    pub y: Vector4<S>,
+    /// The third row of the matrix.
+    pub z: Vector4<S>,
+}
+
+impl<S> Affine2<S> {
+    /// Create a new transformation from its rows.
+    #[inline]
+    pub const fn from_rows(x: Vector3<S>, y: Vector3<S>) -> Affine2<S> {
+        Affine2 { x, y }
+    }
+}
+
+impl<S> Affine3<S> {
+    /// Create a new transformation from its rows.
+    #[inline]
+    pub const fn from_rows(x: Vector4<S>, y: Vector4<S>, z: Vector4<S>) -> Affine3<S> {
+        Affine3 { x, y, z }
+    }
+}
+
+impl<S: BaseFloat> Affine2<S> {
+    /// Create a transformation that applies `linear`, then translates by
+    /// `disp`.
+    pub fn from_parts(linear: Matrix2<S>, disp: Vector2<S>) -> Affine2<S> {
+        Affine2::from_rows(linear.row(0).extend(disp.x), linear.row(1).extend(disp.y))
+    }
+
+    /// Create a translation.
+    #[inline]
+    pub fn from_translation(disp: Vector2<S>) -> Affine2<S> {
+        Affine2::from_parts(Matrix2::identity(), disp)
+    }
+
+    /// Convert a homogeneous matrix, returning `None` if it is not affine.
+    pub fn from_matrix3(m: Matrix3<S>) -> Option<Affine2<S>> {
+        if m.is_affine() {
+            Some(Affine2::from_rows(m.row(0), m.row(1)))
+        } else {
+            None
+        }
+    }
+
+    /// The linear part of the transformation.
+    #[inline]
+    pub fn linear(&self) -> Matrix2<S> {
+        Matrix2::new(self.x.x, self.y.x, self.x.y, self.y.y)
+    }
+
+    /// The translation part of the transformation.
+    #[inline]
+    pub fn translation(&self) -> Vector2<S> {
+        Vector2::new(self.x.z, self.y.z)
+    }
+
+    /// Invert the transformation, returning `None` if its linear part is
+    /// singular. This only inverts the 2x2 linear part.
+    pub fn invert(&self) -> Option<Affine2<S>> {
+        self.linear()
+            .invert()
+            .map(|inv| Affine2::from_parts(inv, -(inv * self.translation())))
+    }
+
+    /// Convert to a uniformly scaled rotation and a translation.
+    ///
+    /// Returns `None` if the linear part contains a shear, a non-uniform
+    /// scale or a reflection, or if it is singular.
+    pub fn to_decomposed(&self) -> Option<Decomposed<Vector2<S>, Basis2<S>>> {
+        let linear = self.linear();
+        let scale = linear.x.magnitude();
+        if ulps_eq!(scale, &S::zero()) {
+            return None;
+        }
+
+        // The second column must be the first one turned a quarter turn
+        // counter-clockwise.
+        let x = linear.x / scale;
+        if !ulps_eq!(Vector2::new(-x.y, x.x), &(linear.y / scale)) {
+            return None;
+        }
+
+        Some(Decomposed {
+            scale,
+            rot: Basis2::from_angle(Rad::atan2(x.y, x.x)),
+            disp: self.translation(),
+        })
+    }
+
+    /// Are all entries in the transformation finite.
+    pub fn is_finite(&self) -> bool {
+        self.x.is_finite() && self.y.is_finite()
+    }
+}
+
+impl<S: BaseFloat> Affine3<S> {
+    /// Create a transformation that applies `linear`, then translates by
+    /// `disp`.
+    pub fn from_parts(linear: Matrix3<S>, disp: Vector3<S>) -> Affine3<S> {
+        Affine3::from_rows(
+            linear.row(0).extend(disp.x),
+            linear.row(1).extend(disp.y),
+            linear.row(2).extend(disp.z),
+        )
+    }
+
+    /// Create a translation.
+    #[inline]
+    pub fn from_translation(disp: Vector3<S>) -> Affine3<S> {
+        Affine3::from_parts(Matrix3::identity(), disp)
+    }
+
+    /// Convert a homogeneous matrix, returning `None` if it is not affine.
+    pub fn from_matrix4(m: Matrix4<S>) -> Option<Affine3<S>> {
+        if m.is_affine() {
+            Some(Affine3::from_rows(m.row(0), m.row(1), m.row(2)))
+        } else {
+            None
+        }
+    }
+
+    /// The linear part of the transformation.
+    #[inline]
+    pub fn linear(&self) -> Matrix3<S> {
+        Matrix3::from_cols(
+            Vector3::new(self.x.x, self.y.x, self.z.x),
+            Vector3::new(self.x.y, self.y.y, self.z.y),
+            Vector3::new(self.x.z, self.y.z, self.z.z),
+        )
+    }
+
+    /// The translation part of the transformation.
+    #[inline]
+    pub fn translation(&self) -> Vector3<S> {
+        Vector3::new(self.x.w, self.y.w, self.z.w)
+    }
+
+    /// Invert the transformation, returning `None` if its linear part is
+    /// singular. This only inverts the 3x3 linear part.
+    pub fn invert(&self) -> Option<Affine3<S>> {
+        self.linear()
+            .invert()
+            .map(|inv| Affine3::from_parts(inv, -(inv * self.translation())))
+    }
+
+    /// Convert to a uniformly scaled rotation and a translation.
+    ///
+    /// Returns `None` if the linear part contains a shear, a non-uniform
+    /// scale or a reflection, or if it is singular. See
+    /// `Matrix3::decompose`.
+    pub fn to_decomposed(&self) -> Option<Decomposed<Vector3<S>, Quaternion<S>>> {
+        let dec = self.linear().decompose()?;
+        let scale = dec.scale.x;
+        if dec.is_reflection()
+            || dec.has_shear()
+            || !ulps_eq!(dec.scale.y, &scale)
+            || !ulps_eq!(dec.scale.z, &scale)
+        {
+            return None;
+        }
+
+        Some(Decomposed {
+            scale,
+            rot: dec.rot,
+            disp: self.translation(),
+        })
+    }
+
+    /// Are all entries in the transformation finite.
+    pub fn is_finite(&self) -> bool {
+        self.x.is_finite() && self.y.is_finite() && self.z.is_finite()
+    }
+}
+
+impl<S: BaseFloat> One for Affine2<S> {
+    #[inline]
+    fn one() -> Affine2<S> {
+        Affine2::from_rows(Vector3::unit_x(), Vector3::unit_y())
+    }
+}
+
+impl<S: BaseFloat> One for Affine3<S> {
+    #[inline]
+    fn one() -> Affine3<S> {
+        Affine3::from_rows(Vector4::unit_x(), Vector4::unit_y(), Vector4::unit_z())
+    }
+}
+
+impl_operator!(<S: BaseFloat> Mul<Affine2<S> > for Affine2<S> {
+    fn mul(lhs, rhs) -> Affine2<S> {
+        Affine2::from_parts(
+            lhs.linear() * rhs.linear(),
+            lhs.linear() * rhs.translation() + lhs.translation(),
+        )
+    }
+});
+
+impl_operator!(<S: BaseFloat> Mul<Affine3<S> > for Affine3<S> {
+    fn mul(lhs, rhs) -> Affine3<S> {
+        Affine3::from_parts(
+            lhs.linear() * rhs.linear(),
+            lhs.linear() * rhs.translation() + lhs.translation(),
+        )
+    }
+});
+
+impl<S: BaseFloat> Transform<Point2<S>> for Affine2<S> {
+    fn look_at(eye: Point2<S>, center: Point2<S>, up: Vector2<S>) -> Affine2<S> {
+        Affine2::from_parts(Matrix2::look_at(center - eye, up), Vector2::zero())
+    }
+
+    fn look_at_rh(eye: Point2<S>, center: Point2<S>, up: Vector2<S>) -> Affine2<S> {
+        Affine2::from_parts(Matrix2::look_at(eye - center, up), Vector2::zero())
+    }
+
+    fn look_at_lh(eye: Point2<S>, center: Point2<S>, up: Vector2<S>) -> Affine2<S> {
+        Affine2::from_parts(Matrix2::look_at(center - eye, up), Vector2::zero())
+    }
+
+    #[inline]
+    fn transform_vector(&self, vec: Vector2<S>) -> Vector2<S> {
+        Vector2::new(self.x.truncate().dot(vec), self.y.truncate().dot(vec))
+    }
+
+    #[inline]
+    fn transform_point(&self, point: Point2<S>) -> Point2<S> {
+        let p = point.to_vec().extend(S::one());
+        Point2::new(self.x.dot(p), self.y.dot(p))
+    }
+
+    #[inline]
+    fn concat(&self, other: &Affine2<S>) -> Affine2<S> {
+        self * other
+    }
+
+    #[inline]
+    fn inverse_transform(&self) -> Option<Affine2<S>> {
+        self.invert()
+    }
+}
+
+impl<S: BaseFloat> Transform<Point3<S>> for Affine3<S> {
+    fn look_at(eye: Point3<S>, center: Point3<S>, up: Vector3<S>) -> Affine3<S> {
+        Affine3::from_rows4(Matrix4::look_at_rh(eye, center, up))
+    }
+
+    fn look_at_rh(eye: Point3<S>, center: Point3<S>, up: Vector3<S>) -> Affine3<S> {
+        Affine3::from_rows4(Matrix4::look_at_rh(eye, center, up))
+    }
+
+    fn look_at_lh(eye: Point3<S>, center: Point3<S>, up: Vector3<S>) -> Affine3<S> {
+        Affine3::from_rows4(Matrix4::look_at_lh(eye, center, up))
+    }
+
+    #[inline]
+    fn transform_vector(&self, vec: Vector3<S>) -> Vector3<S> {
+        Vector3::new(
+            self.x.truncate().dot(vec),
+            self.y.truncate().dot(vec),
+            self.z.truncate().dot(vec),
+        )
+    }
+
+    #[inline]
+    fn transform_point(&self, point: Point3<S>) -> Point3<S> {
+        let p = point.to_homogeneous();
+        Point3::new(self.x.dot(p), self.y.dot(p), self.z.dot(p))
+    }
+
+    #[inline]
+    fn concat(&self, other: &Affine3<S>) -> Affine3<S> {
+        self * other
+    }
+
+    #[inline]
+    fn inverse_transform(&self) -> Option<Affine3<S>> {
+        self.invert()
+    }
+}
+
+impl<S: BaseFloat> Affine3<S> {
+    /// The top three rows of a matrix that is known to be affine.
+    #[inline]
+    fn from_rows4(m: Matrix4<S>) -> Affine3<S> {
+        Affine3::from_rows(m.row(0), m.row(1), m.row(2))
+    }
+}
+
+impl<S: BaseFloat> Transform2 for Affine2<S> {
+    type Scalar = S;
+}
+
+impl<S: BaseFloat> Transform3 for Affine3<S> {
+    type Scalar = S;
+}
+
+impl<S: BaseFloat> From<Affine2<S>> for Matrix3<S> {
+    fn from(a: Affine2<S>) -> Matrix3<S> {
+        let mut m: Matrix3<S> = a.linear().into();
+        m.z = a.translation().extend(S::one());
+        m
+    }
+}
+
+impl<S: BaseFloat> From<Affine3<S>> for Matrix4<S> {
+    fn from(a: Affine3<S>) -> Matrix4<S> {
+        let mut m: Matrix4<S> = a.linear().into();
+        m.w = a.translation().extend(S::one());
+        m
+    }
+}
+
+impl<S: BaseFloat, R: Rotation2<Scalar = S>> From<Decomposed<Vector2<S>, R>> for Affine2<S> {
+    fn from(dec: Decomposed<Vector2<S>, R>) -> Affine2<S> {
+        let m: Matrix2<S> = dec.rot.into();
+        Affine2::from_parts(m * dec.scale, dec.disp)
+    }
+}
+
+impl<S: BaseFloat, R: Rotation3<Scalar = S>> From<Decomposed<Vector3<S>, R>> for Affine3<S> {
+    fn from(dec: Decomposed<Vector3<S>, R>) -> Affine3<S> {
+        let m: Matrix3<S> = dec.rot.into();
+        Affine3::from_parts(m * dec.scale, dec.disp)
+    }
+}
+
+macro_rules! impl_approx {
+    ($Affine:ident { $($field:ident),+ }) => {
+        impl<S: BaseFloat> approx::AbsDiffEq for $Affine<S> {
+            type Epsilon = S::Epsilon;
+
+            #[inline]
+            fn default_epsilon() -> S::Epsilon {
+                S::default_epsilon()
+            }
+
+            #[inline]
+            fn abs_diff_eq(&self, other: &Self, epsilon: S::Epsilon) -> bool {
+                $(self.$field.abs_diff_eq(&other.$field, epsilon))&&+
+            }
+        }
+
+        impl<S: BaseFloat> approx::RelativeEq for $Affine<S> {
+            #[inline]
+            fn default_max_relative() -> S::Epsilon {
+                S::default_max_relative()
+            }
+
+            #[inline]
+            fn relative_eq(&self, other: &Self, epsilon: S::Epsilon, max_relative: S::Epsilon) -> bool {
+                $(self.$field.relative_eq(&other.$field, epsilon, max_relative))&&+
+            }
+        }
+
+        impl<S: BaseFloat> approx::UlpsEq for $Affine<S> {
+            #[inline]
+            fn default_max_ulps() -> u32 {
+                S::default_max_ulps()
+            }
+
+            #[inline]
+            fn ulps_eq(&self, other: &Self, epsilon: S::Epsilon, max_ulps: u32) -> bool {
+                $(self.$field.ulps_eq(&other.$field, epsilon, max_ulps))&&+
+            }
+        }
+
+        #[cfg(feature = "bytemuck")]
+        impl_bytemuck_cast!($Affine);
+    };
+}
+
+impl_approx!(Affine2 { x, y });
+impl_approx!(Affine3 { x, y, z });
+
+#[cfg(feature = "mint")]
+impl<S: Clone> From<Affine2<S>> for mint::RowMatrix2x3<S> {
+    fn from(a: Affine2<S>) -> mint::RowMatrix2x3<S> {
+        mint::RowMatrix2x3 {
+            x: a.x.into(),
+            y: a.y.into(),
+        }
+    }
+}
+
+#[cfg(feature = "mint")]
+impl<S> From<mint::RowMatrix2x3<S>> for Affine2<S> {
+    fn from(m: mint::RowMatrix2x3<S>) -> Affine2<S> {
+        Affine2::from_rows(m.x.into(), m.y.into())
+    }
+}
+
+#[cfg(feature = "mint")]
+impl<S: Clone> From<Affine3<S>> for mint::RowMatrix3x4<S> {
+    fn from(a: Affine3<S>) -> mint::RowMatrix3x4<S> {
+        mint::RowMatrix3x4 {
+            x: a.x.into(),
+            y: a.y.into(),
+            z: a.z.into(),
+        }
+    }
+}
+
+#[cfg(feature = "mint")]
+impl<S> From<mint::RowMatrix3x4<S>> for Affine3<S> {
+    fn from(m: mint::RowMatrix3x4<S>) -> Affine3<S> {
+        Affine3::from_rows(m.x.into(), m.y.into(), m.z.into())
+    }
+}
@@ -0,0 +1,369 @@
+// Copyright 2013-2014 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Axis-angle and rotation vector representations of 3D rotations.
+
+use std::iter;
+use std::ops::*;
+
+use num_traits::cast;
+
+use structure::*;
+
+use angle::Rad;
+use approx;
+use euler::Euler;
+use matrix::Matrix3;
+use num::BaseFloat;
+use point::Point3;
+use quaternion::Quaternion;
+use rotation::{Basis3, Rotation, Rotation3};
+use vector::Vector3;
+
+/// A rotation by an angle around an axis.
+///
+/// The axis should be normalized. Rotations converted from other types
+/// always have an angle in the range `[0, pi]`; the identity rotation uses
+/// the _x_ axis.
+///
+/// This type is marked as `#[repr(C)]`.
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct AxisAngle<S> {
+    /// The axis of rotation.
+    pub axis: Vector3<S>,
+    /// The angle of rotation around the axis.
+    pub angle: Rad<S>,
+}
+
+/// A rotation vector, also known as a Rodrigues vector: the axis of a
+/// rotation scaled by its angle in radians.
+///
+/// Unlike `AxisAngle`, this representation has no special cases: the
+/// identity is the zero vector. Rotations converted from other types always
+/// have a magnitude in the range `[0, pi]`.
+///
+/// This type is marked as `#[repr(C)]`.
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct RotationVector<S> {
+    /// The axis of rotation, scaled by the angle.
+    pub v: Vector3<S>,
+}
+
+impl<S> AxisAngle<S> {
+    /// Construct a rotation from an axis and an angle.
+    #[inline]
+    pub const fn new(axis: Vector3<S>, angle: Rad<S>) -> AxisAngle<S> {
+        AxisAngle { axis, angle }
+    }
+}
+
+impl<S> RotationVector<S> {
+    /// Construct a rotation from a rotation vector.
+    #[inline]
+    pub const fn new(v: Vector3<S>) -> RotationVector<S> {
+        RotationVector { v }
+    }
+}
+
+impl<S: BaseFloat> RotationVector<S> {
+    /// The angle of the rotation.
+    #[inline]
+    pub fn angle(self) -> Rad<S> {
+        Rad(self.v.magnitude())
+    }
+}
+
+impl<S: BaseFloat> From<AxisAngle<S>> for Quaternion<S> {
+    #[inline]
+    fn from(src: AxisAngle<S>) -> Quaternion<S> {
+        Quaternion::from_axis_angle(src.axis, src.angle)
+    }
+}
+
+impl<S: BaseFloat> From<Quaternion<S>> for AxisAngle<S> {
+    fn from(src: Quaternion<S>) -> AxisAngle<S> {
+        // Pick the hemisphere with a non-negative scalar part, so that the
+        // angle is at most half a turn.
+        let q = if src.s < S::zero() { -src } else { src };
+        let sin = q.v.magnitude();
+
+        if sin == S::zero() {
+            AxisAngle::new(Vector3::unit_x(), Rad::zero())
+        } else {
+            // Unlike acos(s), this stays accurate close to 0 and pi.
+            AxisAngle::new(q.v / sin, Rad::atan2(sin, q.s) * cast(2).unwrap())
+        }
+    }
+}
+
+impl<S: BaseFloat> From<RotationVector<S>> for Quaternion<S> {
+    #[inline]
+    fn from(src: RotationVector<S>) -> Quaternion<S> {
+        let half: S = cast(0.5f64).unwrap();
+        Quaternion::from_sv(S::zero(), src.v * half).exp()
+    }
+}
+
+impl<S: BaseFloat> From<Quaternion<S>> for RotationVector<S> {
+    #[inline]
+    fn from(src: Quaternion<S>) -> RotationVector<S> {
+        let q = if src.s < S::zero() { -src } else { src };
+        RotationVector::new(q.ln().v * cast(2).unwrap())
+    }
+}
+
+impl<S: BaseFloat> From<AxisAngle<S>> for RotationVector<S> {
+    #[inline]
+    fn from(src: AxisAngle<S>) -> RotationVector<S> {
+        RotationVector::new(src.axis * src.angle.0)
+    }
+}
+
+impl<S: BaseFloat> From<RotationVector<S>> for AxisAngle<S> {
+    fn from(src: RotationVector<S>) -> AxisAngle<S> {
+        let angle = src.v.magnitude();
+        if angle == S::zero() {
+            AxisAngle::new(Vector3::unit_x(), Rad::zero())
+        } else {
+            AxisAngle::new(src.v / angle, Rad(angle))
+        }
+    }
+}
+
+impl<S: BaseFloat> AxisAngle<S> {
+    #[inline]
+    fn inverse(self) -> AxisAngle<S> {
+        AxisAngle::new(self.axis, -self.angle)
+    }
+}
+
+impl<S: BaseFloat> RotationVector<S> {
+    #[inline]
+    fn inverse(self) -> RotationVector<S> {
+        RotationVector::new(-self.v)
+    }
+}
+
+impl<S: BaseFloat> Rotation3 for AxisAngle<S> {
+    type Scalar = S;
+
+    #[inline]
+    fn from_axis_angle<A: Into<Rad<S>>>(axis: Vector3<S>, angle: A) -> AxisAngle<S> {
+        AxisAngle::new(axis, angle.into())
+    }
+}
+
+impl<S: BaseFloat> Rotation3 for RotationVector<S> {
+    type Scalar = S;
+
+    #[inline]
+    fn from_axis_angle<A: Into<Rad<S>>>(axis: Vector3<S>, angle: A) -> RotationVector<S> {
+        RotationVector::new(axis * angle.into().0)
+    }
+}
+
+impl<S: BaseFloat> One for AxisAngle<S> {
+    #[inline]
+    fn one() -> AxisAngle<S> {
+        AxisAngle::new(Vector3::unit_x(), Rad::zero())
+    }
+}
+
+impl<S: BaseFloat> One for RotationVector<S> {
+    #[inline]
+    fn one() -> RotationVector<S> {
+        RotationVector::new(Vector3::zero())
+    }
+}
+
+macro_rules! impl_rotation {
+    ($Rotation:ident) => {
+        impl<S: BaseFloat> $Rotation<S> {
+            #[inline]
+            fn to_quaternion(self) -> Quaternion<S> {
+                self.into()
+            }
+        }
+
+        impl_operator!(<S: BaseFloat> Mul<$Rotation<S> > for $Rotation<S> {
+            fn mul(lhs, rhs) -> $Rotation<S> {
+                (lhs.to_quaternion() * rhs.to_quaternion()).into()
+            }
+        });
+
+        impl<S: BaseFloat> iter::Product<$Rotation<S>> for $Rotation<S> {
+            #[inline]
+            fn product<I: Iterator<Item = $Rotation<S>>>(iter: I) -> $Rotation<S> {
+                iter.map(Quaternion::from).product::<Quaternion<S>>().into()
+            }
+        }
+
+        impl<'a, S: 'a + BaseFloat> iter::Product<&'a $Rotation<S>> for $Rotation<S> {
+            #[inline]
+            fn product<I: Iterator<Item = &'a $Rotation<S>>>(iter: I) -> $Rotation<S> {
+                iter.map(|&r| Quaternion::from(r)).product::<Quaternion<S>>().into()
+            }
+        }
+
+        impl<S: BaseFloat> Rotation for $Rotation<S> {
+            type Space = Point3<S>;
+
+            #[inline]
+            fn look_at(dir: Vector3<S>, up: Vector3<S>) -> $Rotation<S> {
+                Quaternion::look_at(dir, up).into()
+            }
+
+            #[inline]
+            fn between_vectors(a: Vector3<S>, b: Vector3<S>) -> $Rotation<S> {
+                Quaternion::between_vectors(a, b).into()
+            }
+
+            #[inline]
+            fn rotate_vector(&self, vec: Vector3<S>) -> Vector3<S> {
+                Quaternion::from(*self).rotate_vector(vec)
+            }
+
+            #[inline]
+            fn invert(&self) -> $Rotation<S> {
+                self.inverse()
+            }
+        }
+
+        impl<S: BaseFloat> From<$Rotation<S>> for Matrix3<S> {
+            #[inline]
+            fn from(src: $Rotation<S>) -> Matrix3<S> {
+                Quaternion::from(src).into()
+            }
+        }
+
+        impl<S: BaseFloat> From<Matrix3<S>> for $Rotation<S> {
+            /// Convert a rotation matrix. The matrix must be orthogonal.
+            #[inline]
+            fn from(src: Matrix3<S>) -> $Rotation<S> {
+                Quaternion::from(src).into()
+            }
+        }
+
+        impl<S: BaseFloat> From<$Rotation<S>> for Basis3<S> {
+            #[inline]
+            fn from(src: $Rotation<S>) -> Basis3<S> {
+                Quaternion::from(src).into()
+            }
+        }
+
+        impl<S: BaseFloat> From<Basis3<S>> for $Rotation<S> {
+            #[inline]
+            fn from(src: Basis3<S>) -> $Rotation<S> {
+                Quaternion::from(src).into()
+            }
+        }
+
+        impl<S: BaseFloat> From<Euler<Rad<S>>> for $Rotation<S> {
+            #[inline]
+            fn from(src: Euler<Rad<S>>) -> $Rotation<S> {
+                Quaternion::from(src).into()
+            }
+        }
+
+        #[cfg(feature = "bytemuck")]
+        impl_bytemuck_cast!($Rotation);
+    };
+}
+
+impl_rotation!(AxisAngle);
+impl_rotation!(RotationVector);
+
+impl<S: BaseFloat> approx::AbsDiffEq for AxisAngle<S> {
+    type Epsilon = S::Epsilon;
+
+    #[inline]
+    fn default_epsilon() -> S::Epsilon {
+        S::default_epsilon()
+    }
+
+    #[inline]
+    fn abs_diff_eq(&self, other: &Self, epsilon: S::Epsilon) -> bool {
+        Vector3::abs_diff_eq(&self.axis, &other.axis, epsilon)
+            && Rad::abs_diff_eq(&self.angle, &other.angle, epsilon)
+    }
+}
+
+impl<S: BaseFloat> approx::RelativeEq for AxisAngle<S> {
+    #[inline]
+    fn default_max_relative() -> S::Epsilon {
+        S::default_max_relative()
+    }
+
+    #[inline]
+    fn relative_eq(&self, other: &Self, epsilon: S::Epsilon, max_relative: S::Epsilon) -> bool {
+        Vector3::relative_eq(&self.axis, &other.axis, epsilon, max_relative)
+            && Rad::relative_eq(&self.angle, &other.angle, epsilon, max_relative)
+    }
+}
+
+impl<S: BaseFloat> approx::UlpsEq for AxisAngle<S> {
+    #[inline]
+    fn default_max_ulps() -> u32 {
+        S::default_max_ulps()
+    }
+
+    #[inline]
+    fn ulps_eq(&self, other: &Self, epsilon: S::Epsilon, max_ulps: u32) -> bool {
+        Vector3::ulps_eq(&self.axis, &other.axis, epsilon, max_ulps)
+            && Rad::ulps_eq(&self.angle, &other.angle, epsilon, max_ulps)
+    }
+}
+
+impl<S: BaseFloat> approx::AbsDiffEq for RotationVector<S> {
+    type Epsilon = S::Epsilon;
+
+    #[inline]
+    fn default_epsilon() -> S::Epsilon {
+        S::default_epsilon()
+    }
+
+    #[inline]
+    fn abs_diff_eq(&self, other: &Self, epsilon: S::Epsilon) -> bool {
+        Vector3::abs_diff_eq(&self.v, &other.v, epsilon)
+    }
+}
+
+impl<S: BaseFloat> approx::RelativeEq for RotationVector<S> {
+    #[inline]
+    fn default_max_relative() -> S::Epsilon {
+        S::default_max_relative()
+    }
+
+    #[inline]
+    fn relative_eq(&self, other: &Self, epsilon: S::Epsilon, max_relative: S::Epsilon) -> bool {
+        Vector3::relative_eq(&self.v, &other.v, epsilon, max_relative)
+    }
+}
+
+impl<S: BaseFloat> approx::UlpsEq for RotationVector<S> {
+    #[inline]
+    fn default_max_ulps() -> u32 {
+        S::default_max_ulps()
+    }
+
+    #[inline]
+    fn ulps_eq(&self, other: &Self, epsilon: S::Epsilon, max_ulps: u32) -> bool {
+        Vector3::ulps_eq(&self.v, &other.v, epsilon, max_ulps)
+    }
+}
@@ -0,0 +1,360 @@
+// Copyright 2013-2014 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Dual quaternions, for representing rigid transformations.
+
+use std::ops::*;
+
+use num_traits::cast;
+
+use structure::*;
+
+use approx;
+use matrix::{Matrix3, Matrix4};
+use num::BaseFloat;
+use point::Point3;
+use quaternion::Quaternion;
+use rotation::Rotation;
+use transform::{Decomposed, Transform, Transform3};
+use vector::Vector3;
+
+/// A [dual quaternion](https://en.wikipedia.org/wiki/Dual_quaternion),
+/// `real + ε * dual` where `ε² = 0`.
+///
+/// A unit dual quaternion, which has a unit real part and a dual part that is
+/// orthogonal to it, represents a rigid transformation: a rotation followed
+/// by a translation. Multiplying two of them composes the transformations,
+/// with the right hand side applied first. Unlike matrices, unit dual
+/// quaternions can be blended without introducing scale or shear, which
+/// makes them well suited to skinning.
+///
+/// This type is marked as `#[repr(C)]`.
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct DualQuaternion<S> {
+    /// The real part, which holds the rotation.
+    pub real: Quaternion<S>,
+    /// The dual part, which holds the translation.
+    pub dual: Quaternion<S>,
+}
+
+impl<S> DualQuaternion<S> {
+    /// Construct a new dual quaternion from its real and dual parts.
+    #[inline]
+    pub const fn new(real: Quaternion<S>, dual: Quaternion<S>) -> DualQuaternion<S> {
+        DualQuaternion { real, dual }
+    }
+}
+
+impl<S: BaseFloat> DualQuaternion<S> {
+    /// Construct a dual quaternion that rotates by `rot` and then translates
+    /// by `disp`. The rotation should be normalized.
+    #[inline]
+    pub fn from_rotation_translation(rot: Quaternion<S>, disp: Vector3<S>) -> DualQuaternion<S> {
+        let half: S = cast(0.5f64).unwrap();
+        DualQuaternion::new(rot, Quaternion::from_sv(S::zero(), disp) * rot * half)
+    }
+
+    /// Construct a dual quaternion that only rotates. The rotation should be
+    /// normalized.
+    #[inline]
+    pub fn from_rotation(rot: Quaternion<S>) -> DualQuaternion<S> {
+        DualQuaternion::new(rot, Quaternion::zero())
+    }
+
+    /// Construct a dual quaternion that only translates.
+    #[inline]
+    pub fn from_translation(disp: Vector3<S>) -> DualQuaternion<S> {
+        DualQuaternion::from_rotation_translation(Quaternion::one(), disp)
+    }
+
+    /// The rotation of a unit dual quaternion.
+    #[inline]
+    pub fn rotation(self) -> Quaternion<S> {
+        self.real
+    }
+
+    /// The translation of a unit dual quaternion.
+    #[inline]
+    pub fn translation(self) -> Vector3<S> {
+        let two: S = cast(2).unwrap();
+        (self.dual * self.real.conjugate()).v * two
+    }
+
+    /// The quaternion conjugate of both parts. For a unit dual quaternion
+    /// this is also its inverse.
+    #[inline]
+    pub fn conjugate(self) -> DualQuaternion<S> {
+        DualQuaternion::new(self.real.conjugate(), self.dual.conjugate())
+    }
+
+    /// The multiplicative inverse. The real part must not be zero.
+    pub fn invert(self) -> DualQuaternion<S> {
+        let real = self.real.conjugate() / self.real.magnitude2();
+        DualQuaternion::new(real, -(real * self.dual * real))
+    }
+
+    /// Scale the dual quaternion so that it represents a rigid
+    /// transformation, by normalizing the real part and removing the
+    /// component of the dual part that is parallel to it.
+    pub fn normalize(self) -> DualQuaternion<S> {
+        let magnitude = self.real.magnitude();
+        let real = self.real / magnitude;
+        let dual = self.dual / magnitude;
+        DualQuaternion::new(real, dual - real * real.dot(dual))
+    }
+
+    /// Raise a unit dual quaternion to a power, scaling both the angle of
+    /// its rotation and the distance it moves along the screw axis.
+    pub fn powf(self, exponent: S) -> DualQuaternion<S> {
+        // `q` and `-q` represent the same transformation; pick the one with
+        // the smaller rotation angle.
+        let dq = if self.real.s < S::zero() { -self } else { self };
+
+        let sin_half = dq.real.v.magnitude();
+        if sin_half < S::default_epsilon().sqrt() {
+            // Close to a pure translation the screw axis is ill-defined, so
+            // interpolate the rotation and translation separately.
+            let rot = Quaternion::one().nlerp(dq.real, exponent);
+            return DualQuaternion::from_rotation_translation(rot, dq.translation() * exponent);
+        }
+
+        // Screw parameters: rotation by `2 * angle` about `axis`, which has
+        // moment `moment` about the origin, and translation by `2 * pitch`
+        // along it.
+        let angle = sin_half.atan2(dq.real.s);
+        let axis = dq.real.v / sin_half;
+        let pitch = -dq.dual.s / sin_half;
+        let moment = (dq.dual.v - axis * (pitch * dq.real.s)) / sin_half;
+
+        let angle = angle * exponent;
+        let pitch = pitch * exponent;
+        let (s, c) = angle.sin_cos();
+        DualQuaternion::new(
+            Quaternion::from_sv(c, axis * s),
+            Quaternion::from_sv(-pitch * s, moment * s + axis * (pitch * c)),
+        )
+    }
+
+    /// Screw linear interpolation between two unit dual quaternions.
+    ///
+    /// This moves at constant speed along the screw motion that takes `self`
+    /// to `other`, and is the dual quaternion equivalent of `slerp`. Like
+    /// `slerp`, it takes the shortest path.
+    pub fn sclerp(self, mut other: DualQuaternion<S>, amount: S) -> DualQuaternion<S> {
+        if self.real.dot(other.real) < S::zero() {
+            other = -other;
+        }
+
+        self * (self.conjugate() * other).powf(amount)
+    }
+
+    /// Dual quaternion linear blending of unit dual quaternions, as
+    /// described by Kavan et al. in _Geometric Skinning with Approximate Dual
+    /// Quaternion Blending_ (2008).
+    ///
+    /// Each item is a weight and a dual quaternion. Dual quaternions that
+    /// are in the opposite hemisphere to the first one are negated before
+    /// they are summed, so that the blend takes the shortest path. Returns
+    /// `None` if there are no items or the weighted sum is zero.
+    pub fn dlb<I>(items: I) -> Option<DualQuaternion<S>>
+    where
+        I: IntoIterator<Item = (S, DualQuaternion<S>)>,
+    {
+        let mut items = items.into_iter();
+        let (weight, first) = items.next()?;
+
+        let sum = items.fold(first * weight, |sum, (weight, dq)| {
+            if first.real.dot(dq.real) < S::zero() {
+                sum - dq * weight
+            } else {
+                sum + dq * weight
+            }
+        });
+
+        if ulps_eq!(sum.real.magnitude2(), &S::zero()) {
+            None
+        } else {
+            Some(sum.normalize())
+        }
+    }
+
+    /// Are all entries in the dual quaternion finite.
+    pub fn is_finite(&self) -> bool {
+        self.real.is_finite() && self.dual.is_finite()
+    }
+}
+
+impl<S: BaseFloat> One for DualQuaternion<S> {
+    #[inline]
+    fn one() -> DualQuaternion<S> {
+        DualQuaternion::new(Quaternion::one(), Quaternion::zero())
+    }
+}
+
+impl_operator!(<S: BaseFloat> Neg for DualQuaternion<S> {
+    fn neg(dq) -> DualQuaternion<S> {
+        DualQuaternion::new(-dq.real, -dq.dual)
+    }
+});
+
+impl_operator!(<S: BaseFloat> Mul<S> for DualQuaternion<S> {
+    fn mul(lhs, rhs) -> DualQuaternion<S> {
+        DualQuaternion::new(lhs.real * rhs, lhs.dual * rhs)
+    }
+});
+
+impl_operator!(<S: BaseFloat> Div<S> for DualQuaternion<S> {
+    fn div(lhs, rhs) -> DualQuaternion<S> {
+        DualQuaternion::new(lhs.real / rhs, lhs.dual / rhs)
+    }
+});
+
+impl_operator!(<S: BaseFloat> Add<DualQuaternion<S> > for DualQuaternion<S> {
+    fn add(lhs, rhs) -> DualQuaternion<S> {
+        DualQuaternion::new(lhs.real + rhs.real, lhs.dual + rhs.dual)
+    }
+});
+
+impl_operator!(<S: BaseFloat> Sub<DualQuaternion<S> > for DualQuaternion<S> {
+    fn sub(lhs, rhs) -> DualQuaternion<S> {
+        DualQuaternion::new(lhs.real - rhs.real, lhs.dual - rhs.dual)
+    }
+});
+
+impl_operator!(<S: BaseFloat> Mul<DualQuaternion<S> > for DualQuaternion<S> {
+    fn mul(lhs, rhs) -> DualQuaternion<S> {
+        DualQuaternion::new(
+            lhs.real * rhs.real,
+            lhs.real * rhs.dual + lhs.dual * rhs.real,
+        )
+    }
+});
+
+impl<S: BaseFloat> Transform<Point3<S>> for DualQuaternion<S> {
+    fn look_at(eye: Point3<S>, center: Point3<S>, up: Vector3<S>) -> DualQuaternion<S> {
+        #[allow(deprecated)]
+        Decomposed::<Vector3<S>, Quaternion<S>>::look_at(eye, center, up).into()
+    }
+
+    fn look_at_rh(eye: Point3<S>, center: Point3<S>, up: Vector3<S>) -> DualQuaternion<S> {
+        Decomposed::<Vector3<S>, Quaternion<S>>::look_at_rh(eye, center, up).into()
+    }
+
+    fn look_at_lh(eye: Point3<S>, center: Point3<S>, up: Vector3<S>) -> DualQuaternion<S> {
+        Decomposed::<Vector3<S>, Quaternion<S>>::look_at_lh(eye, center, up).into()
+    }
+
+    #[inline]
+    fn transform_vector(&self, vec: Vector3<S>) -> Vector3<S> {
+        self.real.rotate_vector(vec)
+    }
+
+    #[inline]
+    fn transform_point(&self, point: Point3<S>) -> Point3<S> {
+        self.real.rotate_point(point) + self.translation()
+    }
+
+    #[inline]
+    fn concat(&self, other: &DualQuaternion<S>) -> DualQuaternion<S> {
+        self * other
+    }
+
+    fn inverse_transform(&self) -> Option<DualQuaternion<S>> {
+        if ulps_eq!(self.real.magnitude2(), &S::zero()) {
+            None
+        } else {
+            Some(self.invert())
+        }
+    }
+}
+
+impl<S: BaseFloat> Transform3 for DualQuaternion<S> {
+    type Scalar = S;
+}
+
+impl<S: BaseFloat> From<DualQuaternion<S>> for Matrix4<S> {
+    /// Convert a unit dual quaternion to a homogeneous transformation matrix.
+    fn from(dq: DualQuaternion<S>) -> Matrix4<S> {
+        let mut m: Matrix4<S> = Matrix3::from(dq.real).into();
+        m.w = dq.translation().extend(S::one());
+        m
+    }
+}
+
+impl<S: BaseFloat> From<Decomposed<Vector3<S>, Quaternion<S>>> for DualQuaternion<S> {
+    /// Convert the rotation and displacement of a transform to a dual
+    /// quaternion. Dual quaternions cannot represent scale, so the scale of
+    /// the transform is discarded.
+    fn from(dec: Decomposed<Vector3<S>, Quaternion<S>>) -> DualQuaternion<S> {
+        DualQuaternion::from_rotation_translation(dec.rot, dec.disp)
+    }
+}
+
+impl<S: BaseFloat> From<DualQuaternion<S>> for Decomposed<Vector3<S>, Quaternion<S>> {
+    /// Convert a unit dual quaternion to a transform with a scale of one.
+    fn from(dq: DualQuaternion<S>) -> Decomposed<Vector3<S>, Quaternion<S>> {
+        Decomposed {
+            scale: S::one(),
+            rot: dq.real,
+            disp: dq.translation(),
+        }
+    }
+}
+
+impl<S: BaseFloat> approx::AbsDiffEq for DualQuaternion<S> {
+    type Epsilon = S::Epsilon;
+
+    #[inline]
+    fn default_epsilon() -> S::Epsilon {
+        S::default_epsilon()
+    }
+
+    #[inline]
+    fn abs_diff_eq(&self, other: &Self, epsilon: S::Epsilon) -> bool {
+        Quaternion::abs_diff_eq(&self.real, &other.real, epsilon)
+            && Quaternion::abs_diff_eq(&self.dual, &other.dual, epsilon)
+    }
+}
+
+impl<S: BaseFloat> approx::RelativeEq for DualQuaternion<S> {
+    #[inline]
+    fn default_max_relative() -> S::Epsilon {
+        S::default_max_relative()
+    }
+
+    #[inline]
+    fn relative_eq(&self, other: &Self, epsilon: S::Epsilon, max_relative: S::Epsilon) -> bool {
+        Quaternion::relative_eq(&self.real, &other.real, epsilon, max_relative)
+            && Quaternion::relative_eq(&self.dual, &other.dual, epsilon, max_relative)
+    }
+}
+
+impl<S: BaseFloat> approx::UlpsEq for DualQuaternion<S> {
+    #[inline]
+    fn default_max_ulps() -> u32 {
+        S::default_max_ulps()
+    }
+
+    #[inline]
+    fn ulps_eq(&self, other: &Self, epsilon: S::Epsilon, max_ulps: u32) -> bool {
+        Quaternion::ulps_eq(&self.real, &other.real, epsilon, max_ulps)
+            && Quaternion::ulps_eq(&self.dual, &other.dual, epsilon, max_ulps)
+    }
+}
+
+#[cfg(feature = "bytemuck")]
+impl_bytemuck_cast!(DualQuaternion);
@@ -24,10 +24,12 @@ use structure::*;
 
 use angle::Rad;
 use approx;
+use matrix::Matrix3;
 #[cfg(feature = "mint")]
 use mint;
 use num::BaseFloat;
 use quaternion::Quaternion;
+use rotation::Basis3;
 
 /// A set of [Euler angles] representing a rotation in three-dimensional space.
 ///
@@ -36,7 +38,8 @@ use quaternion::Quaternion;
 /// The axis rotation sequence is XYZ. That is, the rotation is first around
 /// the X axis, then the Y axis, and lastly the Z axis (using intrinsic
 /// rotations). Since all three rotation axes are used, the angles are
-/// Tait–Bryan angles rather than proper Euler angles.
+/// Tait–Bryan angles rather than proper Euler angles. For other rotation
+/// orders, use [`EulerAngles`](struct.EulerAngles.html).
 ///
 /// # Ranges
 ///
@@ -75,6 +78,10 @@ use quaternion::Quaternion;
 /// });
 /// ```
 ///
+/// Euler angles can be extracted from a `Quaternion`, `Matrix3` or `Basis3`
+/// with the corresponding `From` conversion. To keep extracted angles close
+/// to a previous value, use [`from_matrix3_near`](#method.from_matrix3_near).
+///
 /// [Euler angles]: https://en.wikipedia.org/wiki/Euler_angles
 /// [gimbal lock]: https://en.wikipedia.org/wiki/Gimbal_lock#Gimbal_lock_in_applied_mathematics
 /// [convert]: #defining-rotations-using-euler-angles
@@ -145,6 +152,391 @@ impl<S: BaseFloat> From<Quaternion<S>> for Euler<Rad<S>> {
     }
 }
 
+impl<S: BaseFloat> From<Matrix3<S>> for Euler<Rad<S>> {
+    fn from(src: Matrix3<S>) -> Euler<Rad<S>> {
+        xyz(EulerAngles::from_matrix3(
+            src,
+            EulerOrder::Xyz,
+            EulerFrame::Intrinsic,
+        ))
+    }
+}
+
+impl<S: BaseFloat> From<Basis3<S>> for Euler<Rad<S>> {
+    fn from(src: Basis3<S>) -> Euler<Rad<S>> {
+        Euler::from(Matrix3::from(src))
+    }
+}
+
+impl<S: BaseFloat> Euler<Rad<S>> {
+    /// Extract both sets of euler angles that produce the rotation matrix.
+    ///
+    /// See [`EulerAngles::from_matrix3_solutions`] for details.
+    ///
+    /// [`EulerAngles::from_matrix3_solutions`]: struct.EulerAngles.html#method.from_matrix3_solutions
+    pub fn from_matrix3_solutions(m: Matrix3<S>) -> [Euler<Rad<S>>; 2] {
+        let [a, b] = EulerAngles::from_matrix3_solutions(m, EulerOrder::Xyz, EulerFrame::Intrinsic);
+        [xyz(a), xyz(b)]
+    }
+
+    /// Extract the euler angles of the rotation matrix that are closest to
+    /// `reference`.
+    ///
+    /// See [`EulerAngles::from_matrix3_near`] for details.
+    ///
+    /// [`EulerAngles::from_matrix3_near`]: struct.EulerAngles.html#method.from_matrix3_near
+    pub fn from_matrix3_near(m: Matrix3<S>, reference: Euler<Rad<S>>) -> Euler<Rad<S>> {
+        xyz(EulerAngles::from_matrix3_near(m, reference.into()))
+    }
+}
+
+/// Convert intrinsic XYZ angles back to an `Euler`.
+fn xyz<A>(angles: EulerAngles<A>) -> Euler<A> {
+    Euler::new(angles.first, angles.second, angles.third)
+}
+
+/// The sequence of axes used by a set of [`EulerAngles`](struct.EulerAngles.html).
+///
+/// The first six sequences rotate around each axis once and produce
+/// Tait–Bryan angles. The last six rotate around the same axis first and
+/// last, and produce proper Euler angles.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum EulerOrder {
+    /// Rotate around _x_, then _y_, then _z_.
+    Xyz,
+    /// Rotate around _x_, then _z_, then _y_.
+    Xzy,
+    /// Rotate around _y_, then _x_, then _z_.
+    Yxz,
+    /// Rotate around _y_, then _z_, then _x_.
+    Yzx,
+    /// Rotate around _z_, then _x_, then _y_.
+    Zxy,
+    /// Rotate around _z_, then _y_, then _x_.
+    Zyx,
+    /// Rotate around _x_, then _y_, then _x_ again.
+    Xyx,
+    /// Rotate around _x_, then _z_, then _x_ again.
+    Xzx,
+    /// Rotate around _y_, then _x_, then _y_ again.
+    Yxy,
+    /// Rotate around _y_, then _z_, then _y_ again.
+    Yzy,
+    /// Rotate around _z_, then _x_, then _z_ again.
+    Zxz,
+    /// Rotate around _z_, then _y_, then _z_ again.
+    Zyz,
+}
+
+impl EulerOrder {
+    /// The indices of the three rotation axes, in the order they are
+    /// written. `0` is the _x_ axis, `1` the _y_ axis and `2` the _z_ axis.
+    pub fn axes(self) -> [usize; 3] {
+        match self {
+            EulerOrder::Xyz => [0, 1, 2],
+            EulerOrder::Xzy => [0, 2, 1],
+            EulerOrder::Yxz => [1, 0, 2],
+            EulerOrder::Yzx => [1, 2, 0],
+            EulerOrder::Zxy => [2, 0, 1],
+            EulerOrder::Zyx => [2, 1, 0],
+            EulerOrder::Xyx => [0, 1, 0],
+            EulerOrder::Xzx => [0, 2, 0],
+            EulerOrder::Yxy => [1, 0, 1],
+            EulerOrder::Yzy => [1, 2, 1],
+            EulerOrder::Zxz => [2, 0, 2],
+            EulerOrder::Zyz => [2, 1, 2],
+        }
+    }
+
+    /// The same axes in the opposite order. Proper Euler sequences are their
+    /// own reverse.
+    pub fn reversed(self) -> EulerOrder {
+        match self {
+            EulerOrder::Xyz => EulerOrder::Zyx,
+            EulerOrder::Xzy => EulerOrder::Yzx,
+            EulerOrder::Yxz => EulerOrder::Zxy,
+            EulerOrder::Yzx => EulerOrder::Xzy,
+            EulerOrder::Zxy => EulerOrder::Yxz,
+            EulerOrder::Zyx => EulerOrder::Xyz,
+            order => order,
+        }
+    }
+
+    /// Returns `true` if the first and last axes are the same.
+    pub fn is_proper(self) -> bool {
+        let axes = self.axes();
+        axes[0] == axes[2]
+    }
+}
+
+/// Whether the axes of a set of [`EulerAngles`](struct.EulerAngles.html)
+/// move with the body being rotated.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum EulerFrame {
+    /// Each rotation is around an axis of the already rotated body. The
+    /// rotations compose as `R = R1 * R2 * R3`.
+    Intrinsic,
+    /// Each rotation is around an axis of the fixed reference frame. The
+    /// rotations compose as `R = R3 * R2 * R1`.
+    Extrinsic,
+}
+
+/// A set of [Euler angles] with a configurable rotation order.
+///
+/// Unlike [`Euler`](struct.Euler.html), which always uses intrinsic XYZ
+/// rotations, this type supports every Tait–Bryan and proper Euler sequence,
+/// each either intrinsic or extrinsic. The angles are stored in the order the
+/// axes are written, so `first` is the angle around `order.axes()[0]`.
+///
+/// Intrinsic rotations in one order are equivalent to extrinsic rotations in
+/// the reverse order with the angles reversed. For example, intrinsic ZYX
+/// (yaw, pitch, roll) is the same rotation as extrinsic XYZ (roll, pitch,
+/// yaw). Use [`to_frame`](#method.to_frame) to switch between the two.
+///
+/// # Ranges
+///
+/// Angles extracted from a quaternion or matrix are in the following ranges:
+///
+/// - first: [-pi, pi]
+/// - second: [-pi/2, pi/2] for Tait–Bryan sequences, [0, pi] for proper
+///   Euler sequences
+/// - third: [-pi, pi]
+///
+/// # Gimbal lock
+///
+/// When the second angle reaches the edge of its range, the first and third
+/// axes line up and only their combined angle can be recovered. In that case
+/// the first angle is set to zero and the third angle carries the rotation.
+///
+/// # Example
+///
+/// A yaw of 30°, followed by a pitch of 10°, as used in aerospace:
+///
+/// ```
+/// use cgmath::{Deg, EulerAngles, EulerFrame, EulerOrder, Quaternion};
+///
+/// let angles = EulerAngles::new(
+///     Deg(30.0),
+///     Deg(10.0),
+///     Deg(0.0),
+///     EulerOrder::Zyx,
+///     EulerFrame::Intrinsic,
+/// );
+/// let rotation = Quaternion::from(angles);
+/// ```
+///
+/// [Euler angles]: https://en.wikipedia.org/wiki/Euler_angles
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct EulerAngles<A> {
+    /// The angle to apply around the first axis.
+    pub first: A,
+    /// The angle to apply around the second axis.
+    pub second: A,
+    /// The angle to apply around the third axis.
+    pub third: A,
+    /// The sequence of axes the angles apply to.
+    pub order: EulerOrder,
+    /// Whether the rotations are intrinsic or extrinsic.
+    pub frame: EulerFrame,
+}
+
+impl<A> EulerAngles<A> {
+    /// Construct a set of euler angles with the given rotation order.
+    pub const fn new(
+        first: A,
+        second: A,
+        third: A,
+        order: EulerOrder,
+        frame: EulerFrame,
+    ) -> EulerAngles<A> {
+        EulerAngles {
+            first,
+            second,
+            third,
+            order,
+            frame,
+        }
+    }
+
+    /// Express the same rotation using the given frame. Switching frames
+    /// reverses both the axis order and the angles.
+    pub fn to_frame(self, frame: EulerFrame) -> EulerAngles<A> {
+        if frame == self.frame {
+            self
+        } else {
+            EulerAngles::new(
+                self.third,
+                self.second,
+                self.first,
+                self.order.reversed(),
+                frame,
+            )
+        }
+    }
+}
+
+impl<S: BaseFloat> EulerAngles<Rad<S>> {
+    /// Extract the euler angles of a unit quaternion in the given rotation
+    /// order.
+    pub fn from_quaternion(
+        q: Quaternion<S>,
+        order: EulerOrder,
+        frame: EulerFrame,
+    ) -> EulerAngles<Rad<S>> {
+        EulerAngles::from_matrix3(Matrix3::from(q), order, frame)
+    }
+
+    /// Extract the euler angles of a rotation matrix in the given rotation
+    /// order.
+    pub fn from_matrix3(
+        m: Matrix3<S>,
+        order: EulerOrder,
+        frame: EulerFrame,
+    ) -> EulerAngles<Rad<S>> {
+        match frame {
+            EulerFrame::Intrinsic => {
+                let [a, b, c] = intrinsic_angles(m, order.axes(), true);
+                EulerAngles::new(a, b, c, order, frame)
+            }
+            EulerFrame::Extrinsic => {
+                // Extrinsic rotations are the intrinsic rotations of the
+                // reversed sequence, so the locked angle moves to the end.
+                let [c, b, a] = intrinsic_angles(m, order.reversed().axes(), false);
+                EulerAngles::new(a, b, c, order, frame)
+            }
+        }
+    }
+}
+
+impl<S: BaseFloat> EulerAngles<Rad<S>> {
+    /// Extract both sets of euler angles that produce the rotation matrix.
+    ///
+    /// The first solution is the one returned by
+    /// [`from_matrix3`](#method.from_matrix3). The second is
+    /// `(first + pi, pi - second, third + pi)` for Tait–Bryan sequences and
+    /// `(first + pi, -second, third + pi)` for proper Euler sequences, with
+    /// the first and third angles wrapped to [-pi, pi]. Its second angle may
+    /// lie outside the range given [above](#ranges).
+    pub fn from_matrix3_solutions(
+        m: Matrix3<S>,
+        order: EulerOrder,
+        frame: EulerFrame,
+    ) -> [EulerAngles<Rad<S>>; 2] {
+        let a = EulerAngles::from_matrix3(m, order, frame);
+        let second = if order.is_proper() {
+            -a.second
+        } else {
+            Rad::turn_div_2() - a.second
+        };
+        let b = EulerAngles::new(
+            a.first.opposite().normalize_signed(),
+            second,
+            a.third.opposite().normalize_signed(),
+            order,
+            frame,
+        );
+        [a, b]
+    }
+
+    /// Extract the euler angles of the rotation matrix that are closest to
+    /// `reference`, using its rotation order and frame.
+    ///
+    /// Both [solutions](#method.from_matrix3_solutions) are considered, and
+    /// each angle is shifted by whole turns to lie within half a turn of the
+    /// matching reference angle. When the reference is the previous value of
+    /// a rotation that changes over time, such as in an editor UI, this keeps
+    /// the angles continuous instead of flipping by 180° or wrapping around.
+    pub fn from_matrix3_near(m: Matrix3<S>, reference: EulerAngles<Rad<S>>) -> EulerAngles<Rad<S>> {
+        let near = |angle: Rad<S>, to: Rad<S>| to + (angle - to).normalize_signed();
+        let unwrap = |e: EulerAngles<Rad<S>>| {
+            EulerAngles::new(
+                near(e.first, reference.first),
+                near(e.second, reference.second),
+                near(e.third, reference.third),
+                e.order,
+                e.frame,
+            )
+        };
+        let distance = |e: EulerAngles<Rad<S>>| {
+            let (a, b, c) = (
+                (e.first - reference.first).0,
+                (e.second - reference.second).0,
+                (e.third - reference.third).0,
+            );
+            a * a + b * b + c * c
+        };
+
+        let [a, b] = EulerAngles::from_matrix3_solutions(m, reference.order, reference.frame);
+        let (a, b) = (unwrap(a), unwrap(b));
+        if distance(b) < distance(a) {
+            b
+        } else {
+            a
+        }
+    }
+}
+
+/// Solve `m = R_i(a) * R_j(b) * R_k(c)` for the intrinsic sequence `axes`.
+///
+/// The axes are relabelled so that the sequence becomes XYZ, or XYX for
+/// proper Euler sequences. An odd relabelling is a reflection, which negates
+/// the angles, so the results are multiplied by its parity. At gimbal lock
+/// either the first or the last angle is set to zero.
+fn intrinsic_angles<S: BaseFloat>(
+    m: Matrix3<S>,
+    axes: [usize; 3],
+    zero_first: bool,
+) -> [Rad<S>; 3] {
+    let (i, j) = (axes[0], axes[1]);
+    let p = [i, j, 3 - i - j];
+    let parity = if (i + 1) % 3 == j {
+        S::one()
+    } else {
+        -S::one()
+    };
+    let r = |row: usize, col: usize| m[p[col]][p[row]];
+    let threshold = S::default_epsilon() * cast(16).unwrap();
+
+    let (a, b, c) = if axes[2] == i {
+        // R = Rx(a) * Ry(b) * Rx(c)
+        let sb = (r(0, 1) * r(0, 1) + r(0, 2) * r(0, 2)).sqrt();
+        let b = Rad::atan2(parity * sb, r(0, 0));
+        if sb > threshold {
+            let a = Rad::atan2(parity * r(1, 0), -parity * r(2, 0));
+            let c = Rad::atan2(parity * r(0, 1), parity * r(0, 2));
+            (a, b, c)
+        } else if zero_first {
+            (Rad::zero(), b, Rad::atan2(-r(1, 2), r(1, 1)))
+        } else {
+            (Rad::atan2(r(2, 1), r(1, 1)), b, Rad::zero())
+        }
+    } else {
+        // R = Rx(a) * Ry(b) * Rz(c)
+        let cb = (r(0, 0) * r(0, 0) + r(0, 1) * r(0, 1)).sqrt();
+        let b = Rad::atan2(r(0, 2), cb);
+        if cb > threshold {
+            let a = Rad::atan2(-r(1, 2), r(2, 2));
+            let c = Rad::atan2(-r(0, 1), r(0, 0));
+            (a, b, c)
+        } else if zero_first {
+            (Rad::zero(), b, Rad::atan2(r(1, 0), r(1, 1)))
+        } else {
+            (Rad::atan2(r(2, 1), r(1, 1)), b, Rad::zero())
+        }
+    };
+
+    [a * parity, b * parity, c * parity]
+}
+
+impl<A> From<Euler<A>> for EulerAngles<A> {
+    fn from(src: Euler<A>) -> EulerAngles<A> {
+        EulerAngles::new(src.x, src.y, src.z, EulerOrder::Xyz, EulerFrame::Intrinsic)
+    }
+}
+
 impl<A: Angle> approx::AbsDiffEq for Euler<A> {
     type Epsilon = A::Epsilon;
 
@@ -189,6 +581,56 @@ impl<A: Angle> approx::UlpsEq for Euler<A> {
     }
 }
 
+impl<A: Angle> approx::AbsDiffEq for EulerAngles<A> {
+    type Epsilon = A::Epsilon;
+
+    #[inline]
+    fn default_epsilon() -> A::Epsilon {
+        A::default_epsilon()
+    }
+
+    #[inline]
+    fn abs_diff_eq(&self, other: &Self, epsilon: A::Epsilon) -> bool {
+        self.order == other.order
+            && self.frame == other.frame
+            && A::abs_diff_eq(&self.first, &other.first, epsilon)
+            && A::abs_diff_eq(&self.second, &other.second, epsilon)
+            && A::abs_diff_eq(&self.third, &other.third, epsilon)
+    }
+}
+
+impl<A: Angle> approx::RelativeEq for EulerAngles<A> {
+    #[inline]
+    fn default_max_relative() -> A::Epsilon {
+        A::default_max_relative()
+    }
+
+    #[inline]
+    fn relative_eq(&self, other: &Self, epsilon: A::Epsilon, max_relative: A::Epsilon) -> bool {
+        self.order == other.order
+            && self.frame == other.frame
+            && A::relative_eq(&self.first, &other.first, epsilon, max_relative)
+            && A::relative_eq(&self.second, &other.second, epsilon, max_relative)
+            && A::relative_eq(&self.third, &other.third, epsilon, max_relative)
+    }
+}
+
+impl<A: Angle> approx::UlpsEq for EulerAngles<A> {
+    #[inline]
+    fn default_max_ulps() -> u32 {
+        A::default_max_ulps()
+    }
+
+    #[inline]
+    fn ulps_eq(&self, other: &Self, epsilon: A::Epsilon, max_ulps: u32) -> bool {
+        self.order == other.order
+            && self.frame == other.frame
+            && A::ulps_eq(&self.first, &other.first, epsilon, max_ulps)
+            && A::ulps_eq(&self.second, &other.second, epsilon, max_ulps)
+            && A::ulps_eq(&self.third, &other.third, epsilon, max_ulps)
+    }
+}
+
 #[cfg(feature = "rand")]
 impl<A> Distribution<Euler<A>> for Standard
 where
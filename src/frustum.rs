@@ -0,0 +1,232 @@
+// Copyright 2013-2014 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! View frustums and culling queries.
+
+use structure::*;
+
+use aabb::Aabb3;
+use approx;
+use geometry::{Plane, Sphere};
+use matrix::Matrix4;
+use num::BaseFloat;
+use point::Point3;
+use projection::{ClipSpace, DepthRange, PerspectiveMode, YAxis};
+use vector::Vector3;
+
+/// The result of testing a shape against a volume.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Relation {
+    /// The shape lies entirely inside the volume.
+    Inside,
+    /// The shape lies entirely outside the volume.
+    Outside,
+    /// The shape straddles the boundary of the volume.
+    ///
+    /// Culling tests are conservative, so this may also be returned for some
+    /// shapes that lie just outside of a corner or edge of the volume.
+    Intersecting,
+}
+
+/// A view frustum, bounded by six planes whose normals point inwards.
+///
+/// A point is inside the frustum if it lies in the positive half-space of
+/// every plane.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Frustum<S> {
+    /// The left clipping plane.
+    pub left: Plane<S>,
+    /// The right clipping plane.
+    pub right: Plane<S>,
+    /// The bottom clipping plane.
+    pub bottom: Plane<S>,
+    /// The top clipping plane.
+    pub top: Plane<S>,
+    /// The near clipping plane.
+    pub near: Plane<S>,
+    /// The far clipping plane.
+    pub far: Plane<S>,
+}
+
+impl<S: BaseFloat> Frustum<S> {
+    /// Extract the clipping planes from a projection matrix, using the method
+    /// described by Gribb and Hartmann in [_Fast Extraction of Viewing Frustum
+    /// Planes from the World-View-Projection Matrix_][gribb-hartmann].
+    ///
+    /// If `m` is a projection matrix the planes are found in view space, and
+    /// if it is a combined view-projection matrix they are found in world
+    /// space. `mode` and `clip` describe how `m` was built; only the depth
+    /// range and Y direction of `clip`, and whether `mode` is reversed, affect
+    /// the result. Orthographic and planar projections use
+    /// `PerspectiveMode::Standard`.
+    ///
+    /// The planes are normalized, so distances measured against them are
+    /// euclidean. A plane that has been pushed out to infinity, such as the
+    /// far plane of an infinite perspective projection, is left with a zero
+    /// normal and a positive constant, so that every point lies inside it.
+    ///
+    /// [gribb-hartmann]: https://www.gamedevs.org/uploads/fast-extraction-viewing-frustum-planes-from-world-view-projection-matrix.pdf
+    pub fn from_matrix4(m: Matrix4<S>, mode: PerspectiveMode, clip: ClipSpace) -> Frustum<S> {
+        let (r0, r1, r2, r3) = (m.row(0), m.row(1), m.row(2), m.row(3));
+        let plane = |v| {
+            let plane = Plane::from(v);
+            if plane.n == Vector3::zero() {
+                return plane;
+            }
+            let magnitude = plane.n.magnitude();
+            Plane::new(plane.n / magnitude, plane.d / magnitude)
+        };
+
+        let (bottom, top) = match clip.y_axis {
+            YAxis::Up => (plane(r3 + r1), plane(r3 - r1)),
+            YAxis::Down => (plane(r3 - r1), plane(r3 + r1)),
+        };
+
+        // Reversed-Z projections map the near plane to 1 and the far plane to
+        // 0, whatever the depth range of the clip space.
+        let (near, far) = match (mode.is_reversed(), clip.depth) {
+            (true, _) => (plane(r3 - r2), plane(r2)),
+            (false, DepthRange::NegativeOneToOne) => (plane(r3 + r2), plane(r3 - r2)),
+            (false, DepthRange::ZeroToOne) => (plane(r2), plane(r3 - r2)),
+        };
+
+        Frustum {
+            left: plane(r3 + r0),
+            right: plane(r3 - r0),
+            bottom,
+            top,
+            near,
+            far,
+        }
+    }
+
+    /// The six clipping planes, in the order left, right, bottom, top, near,
+    /// far.
+    #[inline]
+    pub fn planes(&self) -> [Plane<S>; 6] {
+        [
+            self.left,
+            self.right,
+            self.bottom,
+            self.top,
+            self.near,
+            self.far,
+        ]
+    }
+
+    /// Test if `point` lies inside or on the boundary of the frustum.
+    #[inline]
+    pub fn contains(&self, point: Point3<S>) -> bool {
+        self.planes()
+            .iter()
+            .all(|plane| plane.distance(point) >= S::zero())
+    }
+
+    /// Classify `point` against the frustum. Points lying exactly on a
+    /// clipping plane are considered to be inside.
+    #[inline]
+    pub fn relate_point(&self, point: Point3<S>) -> Relation {
+        if self.contains(point) {
+            Relation::Inside
+        } else {
+            Relation::Outside
+        }
+    }
+
+    /// Classify `sphere` against the frustum.
+    pub fn relate_sphere(&self, sphere: Sphere<S>) -> Relation {
+        let mut relation = Relation::Inside;
+        for plane in self.planes().iter() {
+            let distance = plane.distance(sphere.center);
+            if distance < -sphere.radius {
+                return Relation::Outside;
+            }
+            if distance < sphere.radius {
+                relation = Relation::Intersecting;
+            }
+        }
+        relation
+    }
+
+    /// Classify `aabb` against the frustum.
+    pub fn relate_aabb(&self, aabb: Aabb3<S>) -> Relation {
+        let center = aabb.center();
+        let half_extents = aabb.half_extents();
+
+        let mut relation = Relation::Inside;
+        for plane in self.planes().iter() {
+            // The distance from the center to the corner that lies furthest
+            // along the plane normal.
+            let radius = half_extents.dot(plane.n.map(S::abs));
+            let distance = plane.distance(center);
+            if distance < -radius {
+                return Relation::Outside;
+            }
+            if distance < radius {
+                relation = Relation::Intersecting;
+            }
+        }
+        relation
+    }
+}
+
+impl<S: BaseFloat> approx::AbsDiffEq for Frustum<S> {
+    type Epsilon = S::Epsilon;
+
+    #[inline]
+    fn default_epsilon() -> S::Epsilon {
+        S::default_epsilon()
+    }
+
+    #[inline]
+    fn abs_diff_eq(&self, other: &Self, epsilon: S::Epsilon) -> bool {
+        let (a, b) = (self.planes(), other.planes());
+        a.iter()
+            .zip(b.iter())
+            .all(|(a, b)| Plane::abs_diff_eq(a, b, epsilon))
+    }
+}
+
+impl<S: BaseFloat> approx::RelativeEq for Frustum<S> {
+    #[inline]
+    fn default_max_relative() -> S::Epsilon {
+        S::default_max_relative()
+    }
+
+    #[inline]
+    fn relative_eq(&self, other: &Self, epsilon: S::Epsilon, max_relative: S::Epsilon) -> bool {
+        let (a, b) = (self.planes(), other.planes());
+        a.iter()
+            .zip(b.iter())
+            .all(|(a, b)| Plane::relative_eq(a, b, epsilon, max_relative))
+    }
+}
+
+impl<S: BaseFloat> approx::UlpsEq for Frustum<S> {
+    #[inline]
+    fn default_max_ulps() -> u32 {
+        S::default_max_ulps()
+    }
+
+    #[inline]
+    fn ulps_eq(&self, other: &Self, epsilon: S::Epsilon, max_ulps: u32) -> bool {
+        let (a, b) = (self.planes(), other.planes());
+        a.iter()
+            .zip(b.iter())
+            .all(|(a, b)| Plane::ulps_eq(a, b, epsilon, max_ulps))
+    }
+}
@@ -0,0 +1,420 @@
+// Copyright 2013-2014 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Basic geometric primitives and ray intersection queries.
+
+use num_traits::cast;
+
+use structure::*;
+
+use aabb::{partial_max, partial_min, Aabb3};
+use approx;
+use num::BaseFloat;
+use point::Point3;
+use vector::{Vector3, Vector4};
+
+/// A ray in 3-dimensional space, starting at `origin` and extending
+/// infinitely along `direction`.
+///
+/// The direction does not need to be normalized. Distances returned by the
+/// intersection queries are measured in multiples of `direction`, so they are
+/// only true euclidean distances if the direction has unit length.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Ray3<S> {
+    /// The point the ray starts from.
+    pub origin: Point3<S>,
+    /// The direction the ray travels in.
+    pub direction: Vector3<S>,
+}
+
+/// A plane in 3-dimensional space, consisting of the points `p` that satisfy
+/// `n.dot(p) + d = 0`.
+///
+/// The normal `n` points towards the positive half-space of the plane. If it
+/// has unit length, `d` is the signed distance from the plane to the origin,
+/// measured against the normal.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Plane<S> {
+    /// The normal of the plane.
+    pub n: Vector3<S>,
+    /// The constant term of the plane equation.
+    pub d: S,
+}
+
+/// A sphere in 3-dimensional space.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Sphere<S> {
+    /// The center of the sphere.
+    pub center: Point3<S>,
+    /// The radius of the sphere.
+    pub radius: S,
+}
+
+/// A triangle in 3-dimensional space.
+///
+/// The front face is the one from which the vertices appear in
+/// counter-clockwise order.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Triangle3<S> {
+    /// The first vertex.
+    pub a: Point3<S>,
+    /// The second vertex.
+    pub b: Point3<S>,
+    /// The third vertex.
+    pub c: Point3<S>,
+}
+
+impl<S> Ray3<S> {
+    /// Construct a new ray from an origin and a direction.
+    #[inline]
+    pub const fn new(origin: Point3<S>, direction: Vector3<S>) -> Ray3<S> {
+        Ray3 { origin, direction }
+    }
+}
+
+impl<S: BaseFloat> Ray3<S> {
+    /// The point at distance `t` along the ray.
+    #[inline]
+    pub fn at(self, t: S) -> Point3<S> {
+        self.origin + self.direction * t
+    }
+
+    /// Compute the distance along the ray at which it crosses `plane`.
+    ///
+    /// Returns `None` if the ray is parallel to the plane or points away
+    /// from it.
+    pub fn intersect_plane(self, plane: Plane<S>) -> Option<S> {
+        // Treat the ray as parallel when the angle to the plane is lost in
+        // rounding error, whatever the scale of the normal and direction.
+        let denom = plane.n.dot(self.direction);
+        if denom.abs() <= S::epsilon() * plane.n.magnitude() * self.direction.magnitude() {
+            return None;
+        }
+
+        let t = -plane.distance(self.origin) / denom;
+        if t >= S::zero() {
+            Some(t)
+        } else {
+            None
+        }
+    }
+
+    /// Compute the distance along the ray at which it first meets the
+    /// surface of `sphere`.
+    ///
+    /// If the ray starts inside the sphere, the distance to the point where it
+    /// exits is returned.
+    pub fn intersect_sphere(self, sphere: Sphere<S>) -> Option<S> {
+        let oc = self.origin - sphere.center;
+        let a = self.direction.magnitude2();
+        let b = oc.dot(self.direction);
+        let c = oc.magnitude2() - sphere.radius * sphere.radius;
+
+        let discriminant = b * b - a * c;
+        if discriminant < S::zero() || a == S::zero() {
+            return None;
+        }
+
+        let root = discriminant.sqrt();
+        let near = (-b - root) / a;
+        let far = (-b + root) / a;
+        if near >= S::zero() {
+            Some(near)
+        } else if far >= S::zero() {
+            Some(far)
+        } else {
+            None
+        }
+    }
+
+    /// Compute the intersection of the ray and `triangle` using the
+    /// Möller–Trumbore algorithm.
+    ///
+    /// On a hit, returns the distance along the ray together with the
+    /// barycentric coordinates of the hit point, weighting the vertices `a`,
+    /// `b` and `c` respectively. Both faces of the triangle are considered.
+    ///
+    /// - [Fast, Minimum Storage Ray/Triangle Intersection](https://www.graphics.cornell.edu/pubs/1997/MT97.pdf)
+    pub fn intersect_triangle(self, triangle: Triangle3<S>) -> Option<(S, Vector3<S>)> {
+        let e1 = triangle.b - triangle.a;
+        let e2 = triangle.c - triangle.a;
+
+        // The determinant is the triple product of the edges and direction,
+        // so compare it to their lengths to find a parallel ray or a
+        // degenerate triangle at any scale.
+        let p = self.direction.cross(e2);
+        let det = e1.dot(p);
+        let scale = e1.magnitude() * e2.magnitude() * self.direction.magnitude();
+        if det.abs() <= S::epsilon() * scale {
+            return None;
+        }
+        let inv_det = det.recip();
+
+        let s = self.origin - triangle.a;
+        let u = s.dot(p) * inv_det;
+        if u < S::zero() || u > S::one() {
+            return None;
+        }
+
+        let q = s.cross(e1);
+        let v = self.direction.dot(q) * inv_det;
+        if v < S::zero() || u + v > S::one() {
+            return None;
+        }
+
+        let t = e2.dot(q) * inv_det;
+        if t >= S::zero() {
+            Some((t, Vector3::new(S::one() - u - v, u, v)))
+        } else {
+            None
+        }
+    }
+
+    /// Compute the distance along the ray at which it first meets the
+    /// surface of `aabb`, using the slab method.
+    ///
+    /// If the ray starts inside the box, the distance to the point where it
+    /// exits is returned.
+    pub fn intersect_aabb(self, aabb: Aabb3<S>) -> Option<S> {
+        let mut near = S::neg_infinity();
+        let mut far = S::infinity();
+
+        for i in 0..3 {
+            let (o, dir) = (self.origin[i], self.direction[i]);
+            if dir == S::zero() {
+                if o < aabb.min[i] || o > aabb.max[i] {
+                    return None;
+                }
+            } else {
+                let t1 = (aabb.min[i] - o) / dir;
+                let t2 = (aabb.max[i] - o) / dir;
+                near = partial_max(near, partial_min(t1, t2));
+                far = partial_min(far, partial_max(t1, t2));
+            }
+        }
+
+        if far < near || far < S::zero() {
+            None
+        } else if near >= S::zero() {
+            Some(near)
+        } else {
+            Some(far)
+        }
+    }
+}
+
+impl<S> Plane<S> {
+    /// Construct a plane from a normal and the constant term of its equation.
+    #[inline]
+    pub const fn new(n: Vector3<S>, d: S) -> Plane<S> {
+        Plane { n, d }
+    }
+
+    /// Construct a plane from the coefficients of the equation
+    /// `a * x + b * y + c * z + d = 0`.
+    #[inline]
+    pub const fn from_abcd(a: S, b: S, c: S, d: S) -> Plane<S> {
+        Plane {
+            n: Vector3::new(a, b, c),
+            d,
+        }
+    }
+}
+
+impl<S: BaseFloat> Plane<S> {
+    /// Construct a plane passing through `point`, facing in the direction of
+    /// `normal`.
+    #[inline]
+    pub fn from_point_normal(point: Point3<S>, normal: Vector3<S>) -> Plane<S> {
+        Plane::new(normal, -point.dot(normal))
+    }
+
+    /// Construct the plane passing through three points, with a unit normal
+    /// facing the side from which they appear in counter-clockwise order.
+    ///
+    /// Returns `None` if the points are collinear.
+    pub fn from_points(a: Point3<S>, b: Point3<S>, c: Point3<S>) -> Option<Plane<S>> {
+        Plane::from_point_normal(a, (b - a).cross(c - a)).normalize()
+    }
+
+    /// Scale the plane equation so that the normal has unit length.
+    ///
+    /// Returns `None` if the normal is zero or not finite.
+    pub fn normalize(self) -> Option<Plane<S>> {
+        let magnitude = self.n.magnitude();
+        if magnitude == S::zero() || !magnitude.is_finite() {
+            None
+        } else {
+            Some(Plane::new(self.n / magnitude, self.d / magnitude))
+        }
+    }
+
+    /// The signed distance from the plane to `point`, positive on the side
+    /// the normal points towards.
+    ///
+    /// This is only a true euclidean distance if the normal has unit length.
+    #[inline]
+    pub fn distance(self, point: Point3<S>) -> S {
+        point.dot(self.n) + self.d
+    }
+
+    /// Project `point` onto the plane along its normal.
+    #[inline]
+    pub fn project_point(self, point: Point3<S>) -> Point3<S> {
+        point - self.n * (self.distance(point) / self.n.magnitude2())
+    }
+}
+
+impl<S: BaseFloat> From<Vector4<S>> for Plane<S> {
+    /// Construct a plane from the coefficients `(a, b, c, d)` of the equation
+    /// `a * x + b * y + c * z + d = 0`.
+    #[inline]
+    fn from(v: Vector4<S>) -> Plane<S> {
+        Plane::from_abcd(v.x, v.y, v.z, v.w)
+    }
+}
+
+impl<S: BaseFloat> From<Plane<S>> for Vector4<S> {
+    #[inline]
+    fn from(plane: Plane<S>) -> Vector4<S> {
+        plane.n.extend(plane.d)
+    }
+}
+
+impl<S> Sphere<S> {
+    /// Construct a sphere from its center and radius.
+    #[inline]
+    pub const fn new(center: Point3<S>, radius: S) -> Sphere<S> {
+        Sphere { center, radius }
+    }
+}
+
+impl<S: BaseFloat> Sphere<S> {
+    /// Test if `point` lies inside or on the surface of the sphere.
+    #[inline]
+    pub fn contains(self, point: Point3<S>) -> bool {
+        self.center.distance2(point) <= self.radius * self.radius
+    }
+
+    /// Test if this sphere overlaps `other`.
+    #[inline]
+    pub fn intersects(self, other: Sphere<S>) -> bool {
+        let radii = self.radius + other.radius;
+        self.center.distance2(other.center) <= radii * radii
+    }
+
+    /// The smallest axis-aligned box containing the sphere.
+    #[inline]
+    pub fn aabb(self) -> Aabb3<S> {
+        let r = Vector3::from_value(self.radius);
+        Aabb3::new(self.center - r, self.center + r)
+    }
+}
+
+impl<S> Triangle3<S> {
+    /// Construct a triangle from its three vertices.
+    #[inline]
+    pub const fn new(a: Point3<S>, b: Point3<S>, c: Point3<S>) -> Triangle3<S> {
+        Triangle3 { a, b, c }
+    }
+}
+
+impl<S: BaseFloat> Triangle3<S> {
+    /// The unit normal of the front face, or `None` if the triangle is
+    /// degenerate.
+    #[inline]
+    pub fn normal(self) -> Option<Vector3<S>> {
+        self.plane().map(|plane| plane.n)
+    }
+
+    /// The plane the triangle lies in, facing the same way as the front face.
+    #[inline]
+    pub fn plane(self) -> Option<Plane<S>> {
+        Plane::from_points(self.a, self.b, self.c)
+    }
+
+    /// The area of the triangle.
+    #[inline]
+    pub fn area(self) -> S {
+        let half: S = cast(0.5f64).unwrap();
+        (self.b - self.a).cross(self.c - self.a).magnitude() * half
+    }
+
+    /// The point at the given barycentric coordinates, weighting the vertices
+    /// `a`, `b` and `c` respectively.
+    #[inline]
+    pub fn from_barycentric(self, weights: Vector3<S>) -> Point3<S> {
+        Point3::from_vec(
+            self.a.to_vec() * weights.x + self.b.to_vec() * weights.y + self.c.to_vec() * weights.z,
+        )
+    }
+
+    /// The smallest axis-aligned box containing the triangle.
+    #[inline]
+    pub fn aabb(self) -> Aabb3<S> {
+        Aabb3::new(self.a, self.b).grow(self.c)
+    }
+}
+
+macro_rules! impl_approx {
+    ($T:ident { $($field:ident),+ }) => {
+        impl<S: BaseFloat> approx::AbsDiffEq for $T<S> {
+            type Epsilon = S::Epsilon;
+
+            #[inline]
+            fn default_epsilon() -> S::Epsilon {
+                S::default_epsilon()
+            }
+
+            #[inline]
+            fn abs_diff_eq(&self, other: &Self, epsilon: S::Epsilon) -> bool {
+                $(approx::AbsDiffEq::abs_diff_eq(&self.$field, &other.$field, epsilon))&&+
+            }
+        }
+
+        impl<S: BaseFloat> approx::RelativeEq for $T<S> {
+            #[inline]
+            fn default_max_relative() -> S::Epsilon {
+                S::default_max_relative()
+            }
+
+            #[inline]
+            fn relative_eq(&self, other: &Self, epsilon: S::Epsilon, max_relative: S::Epsilon) -> bool {
+                $(approx::RelativeEq::relative_eq(&self.$field, &other.$field, epsilon, max_relative))&&+
+            }
+        }
+
+        impl<S: BaseFloat> approx::UlpsEq for $T<S> {
+            #[inline]
+            fn default_max_ulps() -> u32 {
+                S::default_max_ulps()
+            }
+
+            #[inline]
+            fn ulps_eq(&self, other: &Self, epsilon: S::Epsilon, max_ulps: u32) -> bool {
+                $(approx::UlpsEq::ulps_eq(&self.$field, &other.$field, epsilon, max_ulps))&&+
+            }
+        }
+    };
+}
+
+impl_approx!(Ray3 { origin, direction });
+impl_approx!(Plane { n, d });
+impl_approx!(Sphere { center, radius });
+impl_approx!(Triangle3 { a, b, c });
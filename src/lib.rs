@@ -78,15 +78,25 @@ pub use approx::*;
 pub use num::*;
 pub use structure::*;
 
-pub use matrix::{Matrix2, Matrix3, Matrix4};
+pub use matrix::{
+    Lu, Matrix2, Matrix2x3, Matrix2x4, Matrix3, Matrix3x2, Matrix3x4, Matrix4, Matrix4x2,
+    Matrix4x3, PolarDecomposition, Qr, Svd, SymmetricEigen,
+};
 pub use quaternion::Quaternion;
 pub use vector::{dot, vec1, vec2, vec3, vec4, Vector1, Vector2, Vector3, Vector4};
 
+pub use aabb::{Aabb2, Aabb3};
+pub use affine::{Affine2, Affine3};
 pub use angle::{Deg, Rad};
-pub use euler::Euler;
+pub use axis_angle::{AxisAngle, RotationVector};
+pub use dual_quaternion::DualQuaternion;
+pub use euler::{Euler, EulerAngles, EulerFrame, EulerOrder};
+pub use frustum::{Frustum, Relation};
+pub use geometry::{Plane, Ray3, Sphere, Triangle3};
 pub use point::{point1, point2, point3, Point1, Point2, Point3};
 pub use rotation::*;
 pub use transform::*;
+pub use unit_complex::UnitComplex;
 
 pub use projection::*;
 
@@ -111,10 +121,17 @@ mod vector;
 #[cfg(feature = "simd")]
 mod vector_simd;
 
+mod aabb;
+mod affine;
 mod angle;
+mod axis_angle;
+mod dual_quaternion;
 mod euler;
+mod frustum;
+mod geometry;
 mod point;
 mod rotation;
 mod transform;
+mod unit_complex;
 
 mod projection;
@@ -29,11 +29,12 @@ use structure::*;
 
 use angle::Rad;
 use approx;
-use euler::Euler;
+use euler::{Euler, EulerAngles, EulerFrame};
 use num::{BaseFloat, BaseNum};
 use point::{Point2, Point3};
 use quaternion::Quaternion;
-use transform::{Transform, Transform2, Transform3};
+use rotation::Basis3;
+use transform::{AffineDecomposition, Transform, Transform2, Transform3};
 use vector::{Vector2, Vector3, Vector4};
 
 #[cfg(feature = "mint")]
@@ -84,6 +85,225 @@ pub struct Matrix4<S> {
     pub w: Vector4<S>,
 }
 
+/// A 2 x 3, column major matrix, with 2 rows and 3 columns
+///
+/// This type is marked as `#[repr(C)]`.
+#[repr(C)]
+#[derive(Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Matrix2x3<S> {
+    /// The first column of the matrix.
+    pub x: Vector2<S>,
+    /// The second column of the matrix.
+    pub y: Vector2<S>,
+    /// The third column of the matrix.
+    pub z: Vector2<S>,
+}
+
+/// A 2 x 4, column major matrix, with 2 rows and 4 columns
+///
+/// This type is marked as `#[repr(C)]`.
+#[repr(C)]
+#[derive(Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Matrix2x4<S> {
+    /// The first column of the matrix.
+    pub x: Vector2<S>,
+    /// The second column of the matrix.
+    pub y: Vector2<S>,
+    /// The third column of the matrix.
+    pub z: Vector2<S>,
+    /// The fourth column of the matrix.
+    pub w: Vector2<S>,
+}
+
+/// A 3 x 2, column major matrix, with 3 rows and 2 columns
+///
+/// This type is marked as `#[repr(C)]`.
+#[repr(C)]
+#[derive(Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Matrix3x2<S> {
+    /// The first column of the matrix.
+    pub x: Vector3<S>,
+    /// The second column of the matrix.
+    pub y: Vector3<S>,
+}
+
+/// A 3 x 4, column major matrix, with 3 rows and 4 columns
+///
+/// This type is marked as `#[repr(C)]`.
+#[repr(C)]
+#[derive(Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Matrix3x4<S> {
+    /// The first column of the matrix.
+    pub x: Vector3<S>,
+    /// The second column of the matrix.
+    pub y: Vector3<S>,
+    /// The third column of the matrix.
+    pub z: Vector3<S>,
+    /// The fourth column of the matrix.
+    pub w: Vector3<S>,
+}
+
+/// A 4 x 2, column major matrix, with 4 rows and 2 columns
+///
+/// This type is marked as `#[repr(C)]`.
+#[repr(C)]
+#[derive(Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Matrix4x2<S> {
+    /// The first column of the matrix.
+    pub x: Vector4<S>,
+    /// The second column of the matrix.
+    pub y: Vector4<S>,
+}
+
+/// A 4 x 3, column major matrix, with 4 rows and 3 columns
+///
+/// This type is marked as `#[repr(C)]`.
+#[repr(C)]
+#[derive(Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Matrix4x3<S> {
+    /// The first column of the matrix.
+    pub x: Vector4<S>,
+    /// The second column of the matrix.
+    pub y: Vector4<S>,
+    /// The third column of the matrix.
+    pub z: Vector4<S>,
+}
+
+/// The eigenvalues and eigenvectors of a symmetric 3 x 3 matrix, the result
+/// of `Matrix3::symmetric_eigen`.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SymmetricEigen<S> {
+    /// The eigenvalues, sorted from largest to smallest.
+    pub values: Vector3<S>,
+    /// The unit eigenvectors, as the columns of the matrix, in the same order
+    /// as `values`. The columns form a right-handed orthonormal basis, so
+    /// the matrix is a rotation.
+    pub vectors: Matrix3<S>,
+}
+
+impl<S: BaseFloat> SymmetricEigen<S> {
+    /// Rebuild the matrix that was decomposed, as `V * diag(values) * V^T`.
+    pub fn recompose(&self) -> Matrix3<S> {
+        self.vectors * Matrix3::from_diagonal(self.values) * self.vectors.transpose()
+    }
+
+    /// The eigenvectors as a rotation matrix, mapping the coordinate axes
+    /// onto the eigenvectors.
+    #[inline]
+    pub fn basis(&self) -> Basis3<S> {
+        Basis3::from_matrix_unchecked(self.vectors)
+    }
+}
+
+/// The singular value decomposition of a 3 x 3 matrix, the result of
+/// `Matrix3::svd`, such that `m == U * diag(singular_values) * V^T`.
+///
+/// Both `u` and `v` are proper rotations. If the matrix contains a
+/// reflection, the last singular value is negative instead.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Svd<S> {
+    /// The left singular vectors.
+    pub u: Basis3<S>,
+    /// The singular values, sorted from largest to smallest magnitude.
+    pub singular_values: Vector3<S>,
+    /// The right singular vectors.
+    pub v: Basis3<S>,
+}
+
+impl<S: BaseFloat> Svd<S> {
+    /// Rebuild the matrix that was decomposed.
+    pub fn recompose(&self) -> Matrix3<S> {
+        let u: &Matrix3<S> = self.u.as_ref();
+        let v: &Matrix3<S> = self.v.as_ref();
+        u * Matrix3::from_diagonal(self.singular_values) * v.transpose()
+    }
+}
+
+/// The polar decomposition of a 3 x 3 matrix, the result of
+/// `Matrix3::polar_decompose`, such that `m == rotation * stretch`.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct PolarDecomposition<S> {
+    /// The rotation closest to the decomposed matrix.
+    pub rotation: Basis3<S>,
+    /// A symmetric matrix. It is positive semi-definite unless the decomposed
+    /// matrix contains a reflection, which is kept here so that `rotation`
+    /// stays a proper rotation.
+    pub stretch: Matrix3<S>,
+}
+
+impl<S: BaseFloat> PolarDecomposition<S> {
+    /// Rebuild the matrix that was decomposed.
+    pub fn recompose(&self) -> Matrix3<S> {
+        self.rotation.as_ref() * self.stretch
+    }
+}
+
+/// The LU decomposition of a square matrix with partial pivoting, such that
+/// `p * m == l * u`. This is the result of `lu` on `Matrix2`, `Matrix3` and
+/// `Matrix4`.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Lu<M> {
+    /// A lower triangular matrix with ones on the diagonal.
+    pub l: M,
+    /// An upper triangular matrix.
+    pub u: M,
+    /// The permutation matrix describing the row swaps.
+    pub p: M,
+}
+
+impl<S: BaseFloat, M: SquareMatrix<Scalar = S>> Lu<M> {
+    /// Solve `m * x == b` for `x` by forward and back substitution.
+    ///
+    /// Returns `None` if the decomposed matrix is singular.
+    pub fn solve(&self, b: M::ColumnRow) -> Option<M::ColumnRow> {
+        let n = M::ColumnRow::len();
+        let mut x = self.p * b;
+        for r in 1..n {
+            for c in 0..r {
+                let lx = self.l[c][r] * x[c];
+                x[r] -= lx;
+            }
+        }
+        back_substitute(&self.u, x)
+    }
+
+    /// The determinant of the decomposed matrix.
+    pub fn determinant(&self) -> S {
+        let n = M::ColumnRow::len();
+        (0..n).fold(self.p.determinant(), |det, i| det * self.u[i][i])
+    }
+}
+
+/// The QR decomposition of a square matrix, such that `m == q * r`. This is
+/// the result of `qr` on `Matrix2`, `Matrix3` and `Matrix4`.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Qr<M> {
+    /// An orthogonal matrix.
+    pub q: M,
+    /// An upper triangular matrix.
+    pub r: M,
+}
+
+impl<S: BaseFloat, M: SquareMatrix<Scalar = S>> Qr<M> {
+    /// Solve `m * x == b` for `x`, as `x == r^-1 * q^T * b`.
+    ///
+    /// Returns `None` if the decomposed matrix is singular.
+    pub fn solve(&self, b: M::ColumnRow) -> Option<M::ColumnRow> {
+        back_substitute(&self.r, self.q.transpose() * b)
+    }
+}
+
 impl<S> Matrix2<S> {
     /// Create a new matrix, providing values for each index.
     #[inline]
@@ -129,6 +349,68 @@ impl<S: BaseFloat> Matrix2<S> {
     pub fn is_finite(&self) -> bool {
         self.x.is_finite() && self.y.is_finite()
     }
+
+    /// The cofactor matrix, where each entry is the signed determinant of the
+    /// matrix left after removing its row and column.
+    #[inline]
+    pub fn cofactor(&self) -> Matrix2<S> {
+        self.adjugate().transpose()
+    }
+
+    /// The adjugate matrix, the transpose of the cofactor matrix. This is the
+    /// inverse multiplied by the determinant, but it also exists for singular
+    /// matrices.
+    #[inline]
+    pub fn adjugate(&self) -> Matrix2<S> {
+        Matrix2::new(self[1][1], -self[0][1], -self[1][0], self[0][0])
+    }
+
+    /// Make the columns of this matrix orthonormal with the Gram-Schmidt
+    /// process: the first column is normalized, and the second is made
+    /// perpendicular to it. The first column keeps its direction, and a
+    /// reflection is kept.
+    ///
+    /// Returns `None` if the matrix is singular.
+    pub fn orthonormalize_gram_schmidt(&self) -> Option<Matrix2<S>> {
+        let x = self.x.magnitude();
+        if x == S::zero() {
+            return None;
+        }
+        let x_axis = self.x / x;
+
+        let y_col = self.y - x_axis * x_axis.dot(self.y);
+        let y = y_col.magnitude();
+        if is_negligible(y, self.y.magnitude()) {
+            return None;
+        }
+
+        Some(Matrix2::from_cols(x_axis, y_col / y))
+    }
+
+    /// Find the orthogonal matrix closest to this one, `M * (M^T * M)^-1/2`.
+    /// Unlike `orthonormalize_gram_schmidt`, this treats both columns the
+    /// same way. A reflection is kept.
+    ///
+    /// Returns `None` if the matrix is singular.
+    pub fn orthonormalize_symmetric(&self) -> Option<Matrix2<S>> {
+        let det = self.determinant();
+        if is_negligible(det, self.x.magnitude() * self.y.magnitude()) {
+            return None;
+        }
+
+        // The closest rotation to a matrix with a positive determinant has a
+        // closed form. A reflection is flipped into a rotation first.
+        let m = if det < S::zero() {
+            Matrix2::from_cols(self.x, -self.y)
+        } else {
+            *self
+        };
+        let mut rot = Matrix2::from_angle(Rad::atan2(m.x.y - m.y.x, m.x.x + m.y.y));
+        if det < S::zero() {
+            rot.y = -rot.y;
+        }
+        Some(rot)
+    }
 }
 
 impl<S> Matrix3<S> {
@@ -272,10 +554,250 @@ impl<S: BaseFloat> Matrix3<S> {
         )
     }
 
+    /// Test if this matrix is a two-dimensional affine transformation,
+    /// that is, if its bottom row is `[0, 0, 1]` up to rounding errors.
+    pub fn is_affine(&self) -> bool {
+        ulps_eq!(self.row(2), &Vector3::unit_z())
+    }
+
+    /// Invert this matrix, assuming that it is a two-dimensional affine
+    /// transformation.
+    ///
+    /// Only the upper left 2x2 block is inverted, and the translation is
+    /// transformed by the result, which is much cheaper than the general
+    /// `invert`. The bottom row is not read; use `is_affine` to check it
+    /// first. Returns `None` if the upper left block is singular.
+    pub fn invert_affine(&self) -> Option<Matrix3<S>> {
+        let linear = Matrix2::new(self.x.x, self.x.y, self.y.x, self.y.y);
+        linear.invert().map(|inv| {
+            let disp = -(inv * self.z.truncate());
+            Matrix3::from_cols(
+                inv.x.extend(S::zero()),
+                inv.y.extend(S::zero()),
+                disp.extend(S::one()),
+            )
+        })
+    }
+
     /// Are all entries in the matrix finite.
     pub fn is_finite(&self) -> bool {
         self.x.is_finite() && self.y.is_finite() && self.z.is_finite()
     }
+
+    /// The cofactor matrix, where each entry is the signed determinant of the
+    /// matrix left after removing its row and column. Each column is the
+    /// cross product of the other two columns.
+    #[inline]
+    pub fn cofactor(&self) -> Matrix3<S> {
+        Matrix3::from_cols(
+            self[1].cross(self[2]),
+            self[2].cross(self[0]),
+            self[0].cross(self[1]),
+        )
+    }
+
+    /// The adjugate matrix, the transpose of the cofactor matrix. This is the
+    /// inverse multiplied by the determinant, but it also exists for singular
+    /// matrices.
+    #[inline]
+    pub fn adjugate(&self) -> Matrix3<S> {
+        self.cofactor().transpose()
+    }
+
+    /// Decompose this matrix into a rotation, a shear and a non-uniform
+    /// scale, such that `self == R * H * S`. The displacement of the result
+    /// is always zero.
+    ///
+    /// The columns are orthonormalized with the Gram-Schmidt process, in the
+    /// order X, Y, Z. If the matrix contains a reflection, it is folded into
+    /// the scale along the X axis.
+    ///
+    /// Returns `None` if the matrix is singular.
+    pub fn decompose(&self) -> Option<AffineDecomposition<S>> {
+        let x = self.x.magnitude();
+        if x == S::zero() {
+            return None;
+        }
+        let mut x_axis = self.x / x;
+
+        let mut xy = x_axis.dot(self.y);
+        let y_col = self.y - x_axis * xy;
+        let y = y_col.magnitude();
+        if is_negligible(y, self.y.magnitude()) {
+            return None;
+        }
+        let y_axis = y_col / y;
+
+        let mut xz = x_axis.dot(self.z);
+        let yz = y_axis.dot(self.z);
+        let z_col = self.z - x_axis * xz - y_axis * yz;
+        let z = z_col.magnitude();
+        if is_negligible(z, self.z.magnitude()) {
+            return None;
+        }
+        let z_axis = z_col / z;
+
+        let mut scale = Vector3::new(x, y, z);
+        if x_axis.cross(y_axis).dot(z_axis) < S::zero() {
+            x_axis = -x_axis;
+            scale.x = -scale.x;
+            xy = -xy;
+            xz = -xz;
+        }
+
+        let rot = Matrix3::from_cols(x_axis, y_axis, z_axis);
+        Some(AffineDecomposition {
+            disp: Vector3::zero(),
+            rot: Quaternion::from(rot).normalize(),
+            shear: Vector3::new(xy / y, xz / z, yz / z),
+            scale,
+        })
+    }
+
+    /// Compute the eigenvalues and eigenvectors of this matrix using the
+    /// cyclic Jacobi method. This is useful for finding the principal axes
+    /// of an inertia tensor or of a covariance matrix.
+    ///
+    /// Returns `None` if the matrix is not symmetric or not finite.
+    pub fn symmetric_eigen(&self) -> Option<SymmetricEigen<S>> {
+        if !self.is_finite() || !self.is_symmetric() {
+            return None;
+        }
+
+        // Remove any asymmetry left within the tolerance of `is_symmetric`.
+        let two: S = cast(2).unwrap();
+        let (values, v) = jacobi_eigen((*self + self.transpose()) / two);
+
+        let mut order = [0, 1, 2];
+        order.sort_by(|&i, &j| values[j].partial_cmp(&values[i]).unwrap());
+
+        let mut vectors = Matrix3::from_cols(v[order[0]], v[order[1]], v[order[2]]);
+        if vectors.determinant() < S::zero() {
+            vectors.z = -vectors.z;
+        }
+
+        Some(SymmetricEigen {
+            values: Vector3::new(values[order[0]], values[order[1]], values[order[2]]),
+            vectors,
+        })
+    }
+
+    /// Make the columns of this matrix orthonormal with the Gram-Schmidt
+    /// process, in the order X, Y, Z. The first column keeps its direction,
+    /// which makes this useful for correcting the drift of a rotation whose
+    /// X axis is the most important, like a camera direction. A reflection
+    /// is kept.
+    ///
+    /// Returns `None` if the matrix is singular.
+    pub fn orthonormalize_gram_schmidt(&self) -> Option<Matrix3<S>> {
+        let x = self.x.magnitude();
+        if x == S::zero() {
+            return None;
+        }
+        let x_axis = self.x / x;
+
+        let y_col = self.y - x_axis * x_axis.dot(self.y);
+        let y = y_col.magnitude();
+        if is_negligible(y, self.y.magnitude()) {
+            return None;
+        }
+        let y_axis = y_col / y;
+
+        let z_col = self.z - x_axis * x_axis.dot(self.z) - y_axis * y_axis.dot(self.z);
+        let z = z_col.magnitude();
+        if is_negligible(z, self.z.magnitude()) {
+            return None;
+        }
+
+        Some(Matrix3::from_cols(x_axis, y_axis, z_col / z))
+    }
+
+    /// Find the orthogonal matrix closest to this one, `M * (M^T * M)^-1/2`,
+    /// from the polar decomposition. Unlike `orthonormalize_gram_schmidt`,
+    /// this treats all columns the same way, so it does not favor any axis
+    /// when correcting the drift of a rotation. A reflection is kept.
+    ///
+    /// Returns `None` if the matrix is singular.
+    pub fn orthonormalize_symmetric(&self) -> Option<Matrix3<S>> {
+        let svd = self.svd()?;
+        if is_negligible(svd.singular_values.z, svd.singular_values.x) {
+            return None;
+        }
+
+        let mut u: Matrix3<S> = svd.u.into();
+        let v: Matrix3<S> = svd.v.into();
+        if svd.singular_values.z < S::zero() {
+            u.z = -u.z;
+        }
+        Some(u * v.transpose())
+    }
+
+    /// Compute the singular value decomposition of this matrix.
+    ///
+    /// The right singular vectors are found with one-sided Jacobi rotations,
+    /// which make the columns of `M * V` orthogonal, and the left singular
+    /// vectors are those columns normalized. This is the same decomposition
+    /// that `pseudo_inverse` uses. Both bases are kept as proper rotations.
+    ///
+    /// Returns `None` if the matrix is not finite.
+    pub fn svd(&self) -> Option<Svd<S>> {
+        if !self.is_finite() {
+            return None;
+        }
+
+        let (mut b, mut v, scale) = svd(self);
+        if v.determinant() < S::zero() {
+            b.z = -b.z;
+            v.z = -v.z;
+        }
+
+        let n1 = b.x.magnitude();
+        let u1 = if n1 > S::zero() {
+            b.x / n1
+        } else {
+            Vector3::unit_x()
+        };
+
+        // When the matrix has rank one or less, `u2` can be any unit vector
+        // perpendicular to `u1`.
+        let c = b.y - u1 * u1.dot(b.y);
+        let n2 = c.magnitude();
+        let u2 = if !is_negligible(n2, n1) {
+            c / n2
+        } else {
+            let axis = if u1.x.abs() < cast(0.5f64).unwrap() {
+                Vector3::unit_x()
+            } else {
+                Vector3::unit_y()
+            };
+            u1.cross(axis).normalize()
+        };
+        let u3 = u1.cross(u2);
+
+        Some(Svd {
+            u: Basis3::from_matrix_unchecked(Matrix3::from_cols(u1, u2, u3)),
+            singular_values: Vector3::new(u1.dot(b.x), u2.dot(b.y), u3.dot(b.z)) * scale,
+            v: Basis3::from_matrix_unchecked(v),
+        })
+    }
+
+    /// Decompose this matrix into a rotation followed by a symmetric stretch,
+    /// such that `self == R * P`. The rotation is the one closest to this
+    /// matrix, which makes it useful for shape matching, and for extracting
+    /// the rotation from a transformation that contains shear or non-uniform
+    /// scale.
+    ///
+    /// Returns `None` if the matrix is not finite.
+    pub fn polar_decompose(&self) -> Option<PolarDecomposition<S>> {
+        let svd = self.svd()?;
+        let u: Matrix3<S> = svd.u.into();
+        let v: Matrix3<S> = svd.v.into();
+
+        Some(PolarDecomposition {
+            rotation: Basis3::from_matrix_unchecked(u * v.transpose()),
+            stretch: v * Matrix3::from_diagonal(svd.singular_values) * v.transpose(),
+        })
+    }
 }
 
 impl<S> Matrix4<S> {
@@ -472,10 +994,271 @@ impl<S: BaseFloat> Matrix4<S> {
         )
     }
 
+    /// Test if this matrix is a three-dimensional affine transformation,
+    /// that is, if its bottom row is `[0, 0, 0, 1]` up to rounding errors.
+    pub fn is_affine(&self) -> bool {
+        ulps_eq!(self.row(3), &Vector4::unit_w())
+    }
+
+    /// Invert this matrix, assuming that it is a three-dimensional affine
+    /// transformation.
+    ///
+    /// Only the upper left 3x3 block is inverted, and the translation is
+    /// transformed by the result, which is much cheaper than the general
+    /// `invert`. The bottom row is not read; use `is_affine` to check it
+    /// first. Returns `None` if the upper left block is singular.
+    pub fn invert_affine(&self) -> Option<Matrix4<S>> {
+        let linear = Matrix3::from_cols(self.x.truncate(), self.y.truncate(), self.z.truncate());
+        linear.invert().map(|inv| {
+            let disp = -(inv * self.w.truncate());
+            Matrix4::from_cols(
+                inv.x.extend(S::zero()),
+                inv.y.extend(S::zero()),
+                inv.z.extend(S::zero()),
+                disp.extend(S::one()),
+            )
+        })
+    }
+
     /// Are all entries in the matrix finite.
     pub fn is_finite(&self) -> bool {
         self.w.is_finite() && self.x.is_finite() && self.y.is_finite() && self.z.is_finite()
     }
+
+    /// The cofactor matrix, where each entry is the signed determinant of the
+    /// matrix left after removing its row and column.
+    #[inline]
+    pub fn cofactor(&self) -> Matrix4<S> {
+        self.adjugate().transpose()
+    }
+
+    /// The adjugate matrix, the transpose of the cofactor matrix. This is the
+    /// inverse multiplied by the determinant, but it also exists for singular
+    /// matrices.
+    pub fn adjugate(&self) -> Matrix4<S> {
+        let t = self.transpose();
+        let cf = |i, j| {
+            let mat = match i {
+                0 => Matrix3::from_cols(t.y.truncate_n(j), t.z.truncate_n(j), t.w.truncate_n(j)),
+                1 => Matrix3::from_cols(t.x.truncate_n(j), t.z.truncate_n(j), t.w.truncate_n(j)),
+                2 => Matrix3::from_cols(t.x.truncate_n(j), t.y.truncate_n(j), t.w.truncate_n(j)),
+                3 => Matrix3::from_cols(t.x.truncate_n(j), t.y.truncate_n(j), t.z.truncate_n(j)),
+                _ => panic!("out of range"),
+            };
+            let sign = if (i + j) & 1 == 1 {
+                -S::one()
+            } else {
+                S::one()
+            };
+            mat.determinant() * sign
+        };
+
+        #[cfg_attr(rustfmt, rustfmt_skip)]
+        Matrix4::new(
+            cf(0, 0), cf(0, 1), cf(0, 2), cf(0, 3),
+            cf(1, 0), cf(1, 1), cf(1, 2), cf(1, 3),
+            cf(2, 0), cf(2, 1), cf(2, 2), cf(2, 3),
+            cf(3, 0), cf(3, 1), cf(3, 2), cf(3, 3),
+        )
+    }
+
+    /// The matrix that transforms the normals of a surface transformed by
+    /// this matrix: the cofactor matrix of the upper left 3x3 block.
+    ///
+    /// This is the usual inverse transpose multiplied by the determinant, so
+    /// it also works for singular transformations, like a projection onto a
+    /// plane. It maps the cross product of two tangents to the cross product
+    /// of the transformed tangents, so normals keep pointing to the same side
+    /// of a surface when the transformation contains a reflection. The
+    /// transformed normals need to be normalized.
+    pub fn normal_matrix(&self) -> Matrix3<S> {
+        Matrix3::from_cols(self.x.truncate(), self.y.truncate(), self.z.truncate()).cofactor()
+    }
+
+    /// Decompose this affine transformation matrix into a displacement, a
+    /// rotation, a shear and a non-uniform scale, such that
+    /// `self == T * R * H * S`. See `Matrix3::decompose` for how the upper
+    /// left 3x3 block is decomposed.
+    ///
+    /// Returns `None` if the bottom row of the matrix is not `[0, 0, 0, 1]`,
+    /// or if the matrix is singular.
+    pub fn decompose(&self) -> Option<AffineDecomposition<S>> {
+        if !self.is_affine() {
+            return None;
+        }
+
+        let linear = Matrix3::from_cols(self.x.truncate(), self.y.truncate(), self.z.truncate());
+        linear.decompose().map(|dec| AffineDecomposition {
+            disp: self.w.truncate(),
+            ..dec
+        })
+    }
+}
+
+impl<S> Matrix2x3<S> {
+    /// Create a new matrix, providing values for each index.
+    #[inline]
+    #[cfg_attr(rustfmt, rustfmt_skip)]
+    pub const fn new(
+        c0r0: S, c0r1: S,
+        c1r0: S, c1r1: S,
+        c2r0: S, c2r1: S,
+    ) -> Matrix2x3<S> {
+        Matrix2x3::from_cols(
+            Vector2::new(c0r0, c0r1),
+            Vector2::new(c1r0, c1r1),
+            Vector2::new(c2r0, c2r1),
+        )
+    }
+
+    /// Create a new matrix, providing columns.
+    #[inline]
+    pub const fn from_cols(c0: Vector2<S>, c1: Vector2<S>, c2: Vector2<S>) -> Matrix2x3<S> {
+        Matrix2x3 {
+            x: c0,
+            y: c1,
+            z: c2,
+        }
+    }
+}
+
+impl<S> Matrix2x4<S> {
+    /// Create a new matrix, providing values for each index.
+    #[inline]
+    #[cfg_attr(rustfmt, rustfmt_skip)]
+    pub const fn new(
+        c0r0: S, c0r1: S,
+        c1r0: S, c1r1: S,
+        c2r0: S, c2r1: S,
+        c3r0: S, c3r1: S,
+    ) -> Matrix2x4<S> {
+        Matrix2x4::from_cols(
+            Vector2::new(c0r0, c0r1),
+            Vector2::new(c1r0, c1r1),
+            Vector2::new(c2r0, c2r1),
+            Vector2::new(c3r0, c3r1),
+        )
+    }
+
+    /// Create a new matrix, providing columns.
+    #[inline]
+    pub const fn from_cols(
+        c0: Vector2<S>,
+        c1: Vector2<S>,
+        c2: Vector2<S>,
+        c3: Vector2<S>,
+    ) -> Matrix2x4<S> {
+        Matrix2x4 {
+            x: c0,
+            y: c1,
+            z: c2,
+            w: c3,
+        }
+    }
+}
+
+impl<S> Matrix3x2<S> {
+    /// Create a new matrix, providing values for each index.
+    #[inline]
+    #[cfg_attr(rustfmt, rustfmt_skip)]
+    pub const fn new(
+        c0r0: S, c0r1: S, c0r2: S,
+        c1r0: S, c1r1: S, c1r2: S,
+    ) -> Matrix3x2<S> {
+        Matrix3x2::from_cols(
+            Vector3::new(c0r0, c0r1, c0r2),
+            Vector3::new(c1r0, c1r1, c1r2),
+        )
+    }
+
+    /// Create a new matrix, providing columns.
+    #[inline]
+    pub const fn from_cols(c0: Vector3<S>, c1: Vector3<S>) -> Matrix3x2<S> {
+        Matrix3x2 { x: c0, y: c1 }
+    }
+}
+
+impl<S> Matrix3x4<S> {
+    /// Create a new matrix, providing values for each index.
+    #[inline]
+    #[cfg_attr(rustfmt, rustfmt_skip)]
+    pub const fn new(
+        c0r0: S, c0r1: S, c0r2: S,
+        c1r0: S, c1r1: S, c1r2: S,
+        c2r0: S, c2r1: S, c2r2: S,
+        c3r0: S, c3r1: S, c3r2: S,
+    ) -> Matrix3x4<S> {
+        Matrix3x4::from_cols(
+            Vector3::new(c0r0, c0r1, c0r2),
+            Vector3::new(c1r0, c1r1, c1r2),
+            Vector3::new(c2r0, c2r1, c2r2),
+            Vector3::new(c3r0, c3r1, c3r2),
+        )
+    }
+
+    /// Create a new matrix, providing columns.
+    #[inline]
+    pub const fn from_cols(
+        c0: Vector3<S>,
+        c1: Vector3<S>,
+        c2: Vector3<S>,
+        c3: Vector3<S>,
+    ) -> Matrix3x4<S> {
+        Matrix3x4 {
+            x: c0,
+            y: c1,
+            z: c2,
+            w: c3,
+        }
+    }
+}
+
+impl<S> Matrix4x2<S> {
+    /// Create a new matrix, providing values for each index.
+    #[inline]
+    #[cfg_attr(rustfmt, rustfmt_skip)]
+    pub const fn new(
+        c0r0: S, c0r1: S, c0r2: S, c0r3: S,
+        c1r0: S, c1r1: S, c1r2: S, c1r3: S,
+    ) -> Matrix4x2<S> {
+        Matrix4x2::from_cols(
+            Vector4::new(c0r0, c0r1, c0r2, c0r3),
+            Vector4::new(c1r0, c1r1, c1r2, c1r3),
+        )
+    }
+
+    /// Create a new matrix, providing columns.
+    #[inline]
+    pub const fn from_cols(c0: Vector4<S>, c1: Vector4<S>) -> Matrix4x2<S> {
+        Matrix4x2 { x: c0, y: c1 }
+    }
+}
+
+impl<S> Matrix4x3<S> {
+    /// Create a new matrix, providing values for each index.
+    #[inline]
+    #[cfg_attr(rustfmt, rustfmt_skip)]
+    pub const fn new(
+        c0r0: S, c0r1: S, c0r2: S, c0r3: S,
+        c1r0: S, c1r1: S, c1r2: S, c1r3: S,
+        c2r0: S, c2r1: S, c2r2: S, c2r3: S,
+    ) -> Matrix4x3<S> {
+        Matrix4x3::from_cols(
+            Vector4::new(c0r0, c0r1, c0r2, c0r3),
+            Vector4::new(c1r0, c1r1, c1r2, c1r3),
+            Vector4::new(c2r0, c2r1, c2r2, c2r3),
+        )
+    }
+
+    /// Create a new matrix, providing columns.
+    #[inline]
+    pub const fn from_cols(c0: Vector4<S>, c1: Vector4<S>, c2: Vector4<S>) -> Matrix4x3<S> {
+        Matrix4x3 {
+            x: c0,
+            y: c1,
+            z: c2,
+        }
+    }
 }
 
 impl<S: BaseFloat> Zero for Matrix2<S> {
@@ -641,11 +1424,7 @@ impl<S: BaseFloat> SquareMatrix for Matrix2<S> {
         if det == S::zero() {
             None
         } else {
-            #[cfg_attr(rustfmt, rustfmt_skip)]
-            Some(Matrix2::new(
-                self[1][1] / det, -self[0][1] / det,
-                -self[1][0] / det, self[0][0] / det,
-            ))
+            Some(self.adjugate() / det)
         }
     }
 
@@ -745,14 +1524,7 @@ impl<S: BaseFloat> SquareMatrix for Matrix3<S> {
         if det == S::zero() {
             None
         } else {
-            Some(
-                Matrix3::from_cols(
-                    self[1].cross(self[2]) / det,
-                    self[2].cross(self[0]) / det,
-                    self[0].cross(self[1]) / det,
-                )
-                .transpose(),
-            )
+            Some((self.cofactor() / det).transpose())
         }
     }
 
@@ -871,39 +1643,7 @@ impl<S: BaseFloat> SquareMatrix for Matrix4<S> {
         if det == S::zero() {
             None
         } else {
-            let inv_det = S::one() / det;
-            let t = self.transpose();
-            let cf = |i, j| {
-                let mat = match i {
-                    0 => {
-                        Matrix3::from_cols(t.y.truncate_n(j), t.z.truncate_n(j), t.w.truncate_n(j))
-                    }
-                    1 => {
-                        Matrix3::from_cols(t.x.truncate_n(j), t.z.truncate_n(j), t.w.truncate_n(j))
-                    }
-                    2 => {
-                        Matrix3::from_cols(t.x.truncate_n(j), t.y.truncate_n(j), t.w.truncate_n(j))
-                    }
-                    3 => {
-                        Matrix3::from_cols(t.x.truncate_n(j), t.y.truncate_n(j), t.z.truncate_n(j))
-                    }
-                    _ => panic!("out of range"),
-                };
-                let sign = if (i + j) & 1 == 1 {
-                    -S::one()
-                } else {
-                    S::one()
-                };
-                mat.determinant() * sign * inv_det
-            };
-
-            #[cfg_attr(rustfmt, rustfmt_skip)]
-            Some(Matrix4::new(
-                cf(0, 0), cf(0, 1), cf(0, 2), cf(0, 3),
-                cf(1, 0), cf(1, 1), cf(1, 2), cf(1, 3),
-                cf(2, 0), cf(2, 1), cf(2, 2), cf(2, 3),
-                cf(3, 0), cf(3, 1), cf(3, 2), cf(3, 3),
-            ))
+            Some(self.adjugate() * (S::one() / det))
         }
     }
     #[cfg(feature = "simd")]
@@ -1115,7 +1855,13 @@ impl<S: BaseFloat> Transform<Point2<S>> for Matrix3<S> {
     }
 
     fn inverse_transform(&self) -> Option<Matrix3<S>> {
-        SquareMatrix::invert(self)
+        // `invert_affine` ignores the bottom row, so only use it when there
+        // are no projective terms at all.
+        if self.row(2) == Vector3::unit_z() {
+            self.invert_affine()
+        } else {
+            SquareMatrix::invert(self)
+        }
     }
 }
 
@@ -1178,7 +1924,13 @@ impl<S: BaseFloat> Transform<Point3<S>> for Matrix4<S> {
     }
 
     fn inverse_transform(&self) -> Option<Matrix4<S>> {
-        SquareMatrix::invert(self)
+        // `invert_affine` ignores the bottom row, so only use it when there
+        // are no projective terms at all.
+        if self.row(3) == Vector4::unit_w() {
+            self.invert_affine()
+        } else {
+            SquareMatrix::invert(self)
+        }
     }
 }
 
@@ -1194,6 +1946,240 @@ impl<S: BaseFloat> Transform3 for Matrix4<S> {
     type Scalar = S;
 }
 
+macro_rules! impl_linear_solve {
+    ($MatrixN:ident, $VectorN:ident) => {
+        impl<S: BaseFloat> $MatrixN<S> {
+            /// Compute the LU decomposition of this matrix, using partial
+            /// pivoting.
+            pub fn lu(&self) -> Lu<$MatrixN<S>> {
+                lu(self)
+            }
+
+            /// Compute the QR decomposition of this matrix, using Householder
+            /// reflections.
+            pub fn qr(&self) -> Qr<$MatrixN<S>> {
+                qr(self)
+            }
+
+            /// Solve the linear system `self * x == b` for `x`, using the LU
+            /// decomposition. This is more accurate and cheaper than
+            /// multiplying by the inverse.
+            ///
+            /// Returns `None` if the matrix is singular.
+            pub fn solve(&self, b: $VectorN<S>) -> Option<$VectorN<S>> {
+                self.lu().solve(b)
+            }
+
+            /// The condition number of this matrix in the 1-norm, computed
+            /// from its LU decomposition. Large values mean that solutions of
+            /// `self * x == b` are sensitive to small errors in `b`; the
+            /// number of significant digits lost is about the base 10
+            /// logarithm of the result.
+            ///
+            /// Returns infinity if the matrix is singular.
+            pub fn condition_number(&self) -> S {
+                condition_number(self)
+            }
+
+            /// The Moore–Penrose pseudo-inverse of this matrix, computed from
+            /// its singular value decomposition. This is the same as the
+            /// inverse for invertible matrices, and otherwise gives the
+            /// minimum norm least-squares solution of `self * x == b` as
+            /// `self.pseudo_inverse() * b`.
+            ///
+            /// Singular values smaller than `N * epsilon` times the largest
+            /// singular value are treated as zero.
+            pub fn pseudo_inverse(&self) -> $MatrixN<S> {
+                pseudo_inverse(self)
+            }
+        }
+    };
+}
+
+impl_linear_solve!(Matrix2, Vector2);
+impl_linear_solve!(Matrix3, Vector3);
+impl_linear_solve!(Matrix4, Vector4);
+
+macro_rules! impl_exp_ln {
+    ($MatrixN:ident) => {
+        impl<S: BaseFloat> $MatrixN<S> {
+            /// The matrix exponential, `I + M + M^2 / 2! + M^3 / 3! + ...`.
+            /// For a skew-symmetric matrix this is a rotation, and for the
+            /// logarithm of a matrix it is the matrix itself.
+            ///
+            /// If any entry is not finite, every entry of the result is NaN.
+            pub fn exp(&self) -> $MatrixN<S> {
+                exp(self)
+            }
+
+            /// The principal matrix logarithm, the inverse of `exp`.
+            ///
+            /// Returns `None` if the matrix has no real logarithm, which is the
+            /// case when it is singular or has a negative real eigenvalue: for
+            /// example when it contains a reflection, or a rotation by half a
+            /// turn.
+            pub fn ln(&self) -> Option<$MatrixN<S>> {
+                ln(self)
+            }
+
+            /// Interpolate between two transformations, as
+            /// `exp((1 - amount) * ln(self) + amount * ln(other))`. Unlike
+            /// interpolating the entries of the matrices, this does not shear
+            /// rotations or scales between the two.
+            ///
+            /// Returns `None` if either matrix has no real logarithm.
+            pub fn log_lerp(&self, other: $MatrixN<S>, amount: S) -> Option<$MatrixN<S>> {
+                let weighted = [(S::one() - amount, *self), (amount, other)];
+                $MatrixN::linear_combination(weighted.iter().cloned())
+            }
+
+            /// Blend weighted transformations, as the exponential of the
+            /// weighted sum of their logarithms. This is the linear
+            /// combination of transformations of Alexa, "Linear Combination
+            /// of Transformations", SIGGRAPH 2002.
+            ///
+            /// Returns `None` if any matrix has no real logarithm.
+            pub fn linear_combination<I>(iter: I) -> Option<$MatrixN<S>>
+            where
+                I: IntoIterator<Item = (S, $MatrixN<S>)>,
+            {
+                let mut sum = $MatrixN::zero();
+                for (weight, m) in iter {
+                    sum += ln(&m)? * weight;
+                }
+                Some(exp(&sum))
+            }
+        }
+    };
+}
+
+impl_exp_ln!(Matrix3);
+impl_exp_ln!(Matrix4);
+
+macro_rules! impl_rect_matrix {
+    ($MatrixN:ident, $Column:ident { $($field:ident),+ }, $Row:ident { $($row_index:expr),+ },
+     $Transpose:ident, $rows:expr, $cols:expr) => {
+        impl<S: BaseFloat> Zero for $MatrixN<S> {
+            #[inline]
+            fn zero() -> $MatrixN<S> {
+                $MatrixN { $($field: $Column::zero()),+ }
+            }
+
+            #[inline]
+            fn is_zero(&self) -> bool {
+                ulps_eq!(self, &Self::zero())
+            }
+        }
+
+        impl<S: BaseFloat> VectorSpace for $MatrixN<S> {
+            type Scalar = S;
+        }
+
+        impl<S: BaseFloat> Matrix for $MatrixN<S> {
+            type Row = $Row<S>;
+            type Column = $Column<S>;
+            type Transpose = $Transpose<S>;
+
+            #[inline]
+            fn row(&self, r: usize) -> $Row<S> {
+                $Row::new($(self.$field[r]),+)
+            }
+
+            #[inline]
+            fn swap_rows(&mut self, a: usize, b: usize) {
+                $(self.$field.swap_elements(a, b);)+
+            }
+
+            #[inline]
+            fn swap_columns(&mut self, a: usize, b: usize) {
+                unsafe { ptr::swap(&mut self[a], &mut self[b]) };
+            }
+
+            #[inline]
+            fn swap_elements(&mut self, a: (usize, usize), b: (usize, usize)) {
+                let (ac, ar) = a;
+                let (bc, br) = b;
+                unsafe { ptr::swap(&mut self[ac][ar], &mut self[bc][br]) };
+            }
+
+            fn transpose(&self) -> $Transpose<S> {
+                $Transpose::from_cols($(self.row($row_index)),+)
+            }
+        }
+
+        impl<S: BaseFloat> $MatrixN<S> {
+            /// Are all entries in the matrix finite.
+            pub fn is_finite(&self) -> bool {
+                $(self.$field.is_finite())&&+
+            }
+        }
+
+        impl<S: BaseFloat> approx::AbsDiffEq for $MatrixN<S> {
+            type Epsilon = S::Epsilon;
+
+            #[inline]
+            fn default_epsilon() -> S::Epsilon {
+                cast(1.0e-6f64).unwrap()
+            }
+
+            #[inline]
+            fn abs_diff_eq(&self, other: &Self, epsilon: S::Epsilon) -> bool {
+                $($Column::abs_diff_eq(&self.$field, &other.$field, epsilon))&&+
+            }
+        }
+
+        impl<S: BaseFloat> approx::RelativeEq for $MatrixN<S> {
+            #[inline]
+            fn default_max_relative() -> S::Epsilon {
+                S::default_max_relative()
+            }
+
+            #[inline]
+            fn relative_eq(&self, other: &Self, epsilon: S::Epsilon, max_relative: S::Epsilon) -> bool {
+                $($Column::relative_eq(&self.$field, &other.$field, epsilon, max_relative))&&+
+            }
+        }
+
+        impl<S: BaseFloat> approx::UlpsEq for $MatrixN<S> {
+            #[inline]
+            fn default_max_ulps() -> u32 {
+                S::default_max_ulps()
+            }
+
+            #[inline]
+            fn ulps_eq(&self, other: &Self, epsilon: S::Epsilon, max_ulps: u32) -> bool {
+                $($Column::ulps_eq(&self.$field, &other.$field, epsilon, max_ulps))&&+
+            }
+        }
+
+        impl<S: fmt::Debug> fmt::Debug for $MatrixN<S> {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                write!(f, concat!(stringify!($MatrixN), " "))?;
+                <[[S; $rows]; $cols] as fmt::Debug>::fmt(self.as_ref(), f)
+            }
+        }
+
+        #[cfg(feature = "rand")]
+        impl<S> Distribution<$MatrixN<S>> for Standard
+        where
+            Standard: Distribution<$Column<S>>,
+            S: BaseNum,
+        {
+            #[inline]
+            fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> $MatrixN<S> {
+                $MatrixN { $($field: rng.gen()),+ }
+            }
+        }
+    }
+}
+
+impl_rect_matrix!(Matrix2x3, Vector2 { x, y, z }, Vector3 { 0, 1 }, Matrix3x2, 2, 3);
+impl_rect_matrix!(Matrix2x4, Vector2 { x, y, z, w }, Vector4 { 0, 1 }, Matrix4x2, 2, 4);
+impl_rect_matrix!(Matrix3x2, Vector3 { x, y }, Vector2 { 0, 1, 2 }, Matrix2x3, 3, 2);
+impl_rect_matrix!(Matrix3x4, Vector3 { x, y, z, w }, Vector4 { 0, 1, 2 }, Matrix4x3, 3, 4);
+impl_rect_matrix!(Matrix4x2, Vector4 { x, y }, Vector2 { 0, 1, 2, 3 }, Matrix2x4, 4, 2);
+impl_rect_matrix!(Matrix4x3, Vector4 { x, y, z }, Vector3 { 0, 1, 2, 3 }, Matrix3x4, 4, 3);
+
 macro_rules! impl_matrix {
     ($MatrixN:ident, $VectorN:ident { $($field:ident : $row_index:expr),+ }) => {
         impl_operator!(<S: BaseFloat> Neg for $MatrixN<S> {
@@ -1246,20 +2232,6 @@ macro_rules! impl_matrix {
             }
         }
 
-        impl<S: BaseFloat> iter::Product for $MatrixN<S> {
-            #[inline]
-            fn product<I: Iterator<Item=$MatrixN<S>>>(iter: I) -> $MatrixN<S> {
-                iter.fold($MatrixN::identity(), Mul::mul)
-            }
-        }
-
-        impl<'a, S: 'a + BaseFloat> iter::Product<&'a $MatrixN<S>> for $MatrixN<S> {
-            #[inline]
-            fn product<I: Iterator<Item=&'a $MatrixN<S>>>(iter: I) -> $MatrixN<S> {
-                iter.fold($MatrixN::identity(), Mul::mul)
-            }
-        }
-
         impl_scalar_ops!($MatrixN<usize> { $($field),+ });
         impl_scalar_ops!($MatrixN<u8> { $($field),+ });
         impl_scalar_ops!($MatrixN<u16> { $($field),+ });
@@ -1308,17 +2280,56 @@ impl_matrix!(Matrix2, Vector2 { x: 0, y: 1 });
 impl_matrix!(Matrix3, Vector3 { x: 0, y: 1, z: 2 });
 #[cfg_attr(rustfmt, rustfmt_skip)]
 impl_matrix!(Matrix4, Vector4 { x: 0, y: 1, z: 2, w: 3 });
+impl_matrix!(Matrix2x3, Vector2 { x: 0, y: 1, z: 2 });
+#[cfg_attr(rustfmt, rustfmt_skip)]
+impl_matrix!(Matrix2x4, Vector2 { x: 0, y: 1, z: 2, w: 3 });
+impl_matrix!(Matrix3x2, Vector3 { x: 0, y: 1 });
+#[cfg_attr(rustfmt, rustfmt_skip)]
+impl_matrix!(Matrix3x4, Vector3 { x: 0, y: 1, z: 2, w: 3 });
+impl_matrix!(Matrix4x2, Vector4 { x: 0, y: 1 });
+impl_matrix!(Matrix4x3, Vector4 { x: 0, y: 1, z: 2 });
+
+macro_rules! impl_matrix_product {
+    ($MatrixN:ident) => {
+        impl<S: BaseFloat> iter::Product for $MatrixN<S> {
+            #[inline]
+            fn product<I: Iterator<Item = $MatrixN<S>>>(iter: I) -> $MatrixN<S> {
+                iter.fold($MatrixN::identity(), Mul::mul)
+            }
+        }
+
+        impl<'a, S: 'a + BaseFloat> iter::Product<&'a $MatrixN<S>> for $MatrixN<S> {
+            #[inline]
+            fn product<I: Iterator<Item = &'a $MatrixN<S>>>(iter: I) -> $MatrixN<S> {
+                iter.fold($MatrixN::identity(), Mul::mul)
+            }
+        }
+    };
+}
+
+impl_matrix_product!(Matrix2);
+impl_matrix_product!(Matrix3);
+impl_matrix_product!(Matrix4);
 
 macro_rules! impl_mv_operator {
     ($MatrixN:ident, $VectorN:ident { $($field:ident : $row_index:expr),+ }) => {
-        impl_operator!(<S: BaseFloat> Mul<$VectorN<S> > for $MatrixN<S> {
-            fn mul(matrix, vector) -> $VectorN<S> {$VectorN::new($(matrix.row($row_index).dot(vector.clone())),+)}
+        impl_mv_operator!($MatrixN, $VectorN => $VectorN { $($field: $row_index),+ });
+    };
+    ($MatrixN:ident, $VectorIn:ident => $VectorOut:ident { $($field:ident : $row_index:expr),+ }) => {
+        impl_operator!(<S: BaseFloat> Mul<$VectorIn<S> > for $MatrixN<S> {
+            fn mul(matrix, vector) -> $VectorOut<S> {$VectorOut::new($(matrix.row($row_index).dot(vector.clone())),+)}
         });
-    }
+    };
 }
 
 impl_mv_operator!(Matrix2, Vector2 { x: 0, y: 1 });
 impl_mv_operator!(Matrix3, Vector3 { x: 0, y: 1, z: 2 });
+impl_mv_operator!(Matrix2x3, Vector3 => Vector2 { x: 0, y: 1 });
+impl_mv_operator!(Matrix2x4, Vector4 => Vector2 { x: 0, y: 1 });
+impl_mv_operator!(Matrix3x2, Vector2 => Vector3 { x: 0, y: 1, z: 2 });
+impl_mv_operator!(Matrix3x4, Vector4 => Vector3 { x: 0, y: 1, z: 2 });
+impl_mv_operator!(Matrix4x2, Vector2 => Vector4 { x: 0, y: 1, z: 2, w: 3 });
+impl_mv_operator!(Matrix4x3, Vector3 => Vector4 { x: 0, y: 1, z: 2, w: 3 });
 #[cfg(not(feature = "simd"))]
 #[cfg_attr(rustfmt, rustfmt_skip)]
 impl_mv_operator!(Matrix4, Vector4 { x: 0, y: 1, z: 2, w: 3 });
@@ -1370,31 +2381,70 @@ impl_operator!(<S: BaseFloat> Mul<Matrix4<S> > for Matrix4<S> {
     }
 });
 
+macro_rules! impl_mm_operator {
+    ($Lhs:ident * $Rhs:ident => $Output:ident { $($field:ident),+ }) => {
+        impl_operator!(<S: BaseFloat> Mul<$Rhs<S> > for $Lhs<S> {
+            fn mul(lhs, rhs) -> $Output<S> { $Output::from_cols($(lhs * rhs.$field),+) }
+        });
+    };
+}
+
+impl_mm_operator!(Matrix2 * Matrix2x3 => Matrix2x3 { x, y, z });
+impl_mm_operator!(Matrix2 * Matrix2x4 => Matrix2x4 { x, y, z, w });
+impl_mm_operator!(Matrix2x3 * Matrix3x2 => Matrix2 { x, y });
+impl_mm_operator!(Matrix2x3 * Matrix3 => Matrix2x3 { x, y, z });
+impl_mm_operator!(Matrix2x3 * Matrix3x4 => Matrix2x4 { x, y, z, w });
+impl_mm_operator!(Matrix2x4 * Matrix4x2 => Matrix2 { x, y });
+impl_mm_operator!(Matrix2x4 * Matrix4x3 => Matrix2x3 { x, y, z });
+impl_mm_operator!(Matrix2x4 * Matrix4 => Matrix2x4 { x, y, z, w });
+impl_mm_operator!(Matrix3x2 * Matrix2 => Matrix3x2 { x, y });
+impl_mm_operator!(Matrix3x2 * Matrix2x3 => Matrix3 { x, y, z });
+impl_mm_operator!(Matrix3x2 * Matrix2x4 => Matrix3x4 { x, y, z, w });
+impl_mm_operator!(Matrix3 * Matrix3x2 => Matrix3x2 { x, y });
+impl_mm_operator!(Matrix3 * Matrix3x4 => Matrix3x4 { x, y, z, w });
+impl_mm_operator!(Matrix3x4 * Matrix4x2 => Matrix3x2 { x, y });
+impl_mm_operator!(Matrix3x4 * Matrix4x3 => Matrix3 { x, y, z });
+impl_mm_operator!(Matrix3x4 * Matrix4 => Matrix3x4 { x, y, z, w });
+impl_mm_operator!(Matrix4x2 * Matrix2 => Matrix4x2 { x, y });
+impl_mm_operator!(Matrix4x2 * Matrix2x3 => Matrix4x3 { x, y, z });
+impl_mm_operator!(Matrix4x2 * Matrix2x4 => Matrix4 { x, y, z, w });
+impl_mm_operator!(Matrix4x3 * Matrix3x2 => Matrix4x2 { x, y });
+impl_mm_operator!(Matrix4x3 * Matrix3 => Matrix4x3 { x, y, z });
+impl_mm_operator!(Matrix4x3 * Matrix3x4 => Matrix4 { x, y, z, w });
+impl_mm_operator!(Matrix4 * Matrix4x2 => Matrix4x2 { x, y });
+impl_mm_operator!(Matrix4 * Matrix4x3 => Matrix4x3 { x, y, z });
+
 macro_rules! index_operators {
-    ($MatrixN:ident<$S:ident>, $n:expr, $Output:ty, $I:ty) => {
+    ($MatrixN:ident<$S:ident>, $rows:expr, $cols:expr, $Output:ty, $I:ty) => {
         impl<$S> Index<$I> for $MatrixN<$S> {
             type Output = $Output;
 
             #[inline]
-            fn index<'a>(&'a self, i: $I) -> &'a $Output {
-                let v: &[[$S; $n]; $n] = self.as_ref();
+            fn index(&self, i: $I) -> &$Output {
+                let v: &[[$S; $rows]; $cols] = self.as_ref();
                 From::from(&v[i])
             }
         }
 
         impl<$S> IndexMut<$I> for $MatrixN<$S> {
             #[inline]
-            fn index_mut<'a>(&'a mut self, i: $I) -> &'a mut $Output {
-                let v: &mut [[$S; $n]; $n] = self.as_mut();
+            fn index_mut(&mut self, i: $I) -> &mut $Output {
+                let v: &mut [[$S; $rows]; $cols] = self.as_mut();
                 From::from(&mut v[i])
             }
         }
     };
 }
 
-index_operators!(Matrix2<S>, 2, Vector2<S>, usize);
-index_operators!(Matrix3<S>, 3, Vector3<S>, usize);
-index_operators!(Matrix4<S>, 4, Vector4<S>, usize);
+index_operators!(Matrix2<S>, 2, 2, Vector2<S>, usize);
+index_operators!(Matrix3<S>, 3, 3, Vector3<S>, usize);
+index_operators!(Matrix4<S>, 4, 4, Vector4<S>, usize);
+index_operators!(Matrix2x3<S>, 2, 3, Vector2<S>, usize);
+index_operators!(Matrix2x4<S>, 2, 4, Vector2<S>, usize);
+index_operators!(Matrix3x2<S>, 3, 2, Vector3<S>, usize);
+index_operators!(Matrix3x4<S>, 3, 4, Vector3<S>, usize);
+index_operators!(Matrix4x2<S>, 4, 2, Vector4<S>, usize);
+index_operators!(Matrix4x3<S>, 4, 3, Vector4<S>, usize);
 // index_operators!(Matrix2<S>, 2, [Vector2<S>], Range<usize>);
 // index_operators!(Matrix3<S>, 3, [Vector3<S>], Range<usize>);
 // index_operators!(Matrix4<S>, 4, [Vector4<S>], Range<usize>);
@@ -1447,100 +2497,139 @@ where
     }
 }
 
+impl<A> From<EulerAngles<A>> for Matrix3<A::Unitless>
+where
+    A: Angle + Into<Rad<<A as Angle>::Unitless>>,
+{
+    fn from(src: EulerAngles<A>) -> Matrix3<A::Unitless> {
+        let about = |axis: usize, angle: A| match axis {
+            0 => Matrix3::from_angle_x(angle),
+            1 => Matrix3::from_angle_y(angle),
+            _ => Matrix3::from_angle_z(angle),
+        };
+
+        let [i, j, k] = src.order.axes();
+        let (a, b, c) = (
+            about(i, src.first),
+            about(j, src.second),
+            about(k, src.third),
+        );
+        match src.frame {
+            EulerFrame::Intrinsic => a * b * c,
+            EulerFrame::Extrinsic => c * b * a,
+        }
+    }
+}
+
+impl<A> From<EulerAngles<A>> for Matrix4<A::Unitless>
+where
+    A: Angle + Into<Rad<<A as Angle>::Unitless>>,
+{
+    fn from(src: EulerAngles<A>) -> Matrix4<A::Unitless> {
+        Matrix3::from(src).into()
+    }
+}
+
 macro_rules! fixed_array_conversions {
-    ($MatrixN:ident <$S:ident> { $($field:ident : $index:expr),+ }, $n:expr) => {
-        impl<$S> From<$MatrixN<$S>> for [[$S; $n]; $n] {
+    ($MatrixN:ident <$S:ident> { $($field:ident : $index:expr),+ }, $rows:expr, $cols:expr) => {
+        impl<$S> From<$MatrixN<$S>> for [[$S; $rows]; $cols] {
             #[inline]
             fn from(v: $MatrixN<$S>) -> Self {
                 match v { $MatrixN { $($field),+ } => [$($field.into()),+] }
             }
         }
 
-        impl<$S> AsRef<[[$S; $n]; $n]> for $MatrixN<$S> {
+        impl<$S> AsRef<[[$S; $rows]; $cols]> for $MatrixN<$S> {
             #[inline]
-            fn as_ref(&self) -> &[[$S; $n]; $n] {
+            fn as_ref(&self) -> &[[$S; $rows]; $cols] {
                 unsafe { mem::transmute(self) }
             }
         }
 
-        impl<$S> AsMut<[[$S; $n]; $n]> for $MatrixN<$S> {
+        impl<$S> AsMut<[[$S; $rows]; $cols]> for $MatrixN<$S> {
             #[inline]
-            fn as_mut(&mut self) -> &mut [[$S; $n]; $n] {
+            fn as_mut(&mut self) -> &mut [[$S; $rows]; $cols] {
                 unsafe { mem::transmute(self) }
             }
         }
 
-        impl<$S: Copy> From<[[$S; $n]; $n]> for $MatrixN<$S> {
+        impl<$S: Copy> From<[[$S; $rows]; $cols]> for $MatrixN<$S> {
             #[inline]
-            fn from(m: [[$S; $n]; $n]) -> $MatrixN<$S> {
+            fn from(m: [[$S; $rows]; $cols]) -> $MatrixN<$S> {
                 // We need to use a copy here because we can't pattern match on arrays yet
                 $MatrixN { $($field: From::from(m[$index])),+ }
             }
         }
 
-        impl<'a, $S> From<&'a [[$S; $n]; $n]> for &'a $MatrixN<$S> {
+        impl<'a, $S> From<&'a [[$S; $rows]; $cols]> for &'a $MatrixN<$S> {
             #[inline]
-            fn from(m: &'a [[$S; $n]; $n]) -> &'a $MatrixN<$S> {
+            fn from(m: &'a [[$S; $rows]; $cols]) -> &'a $MatrixN<$S> {
                 unsafe { mem::transmute(m) }
             }
         }
 
-        impl<'a, $S> From<&'a mut [[$S; $n]; $n]> for &'a mut $MatrixN<$S> {
+        impl<'a, $S> From<&'a mut [[$S; $rows]; $cols]> for &'a mut $MatrixN<$S> {
             #[inline]
-            fn from(m: &'a mut [[$S; $n]; $n]) -> &'a mut $MatrixN<$S> {
+            fn from(m: &'a mut [[$S; $rows]; $cols]) -> &'a mut $MatrixN<$S> {
                 unsafe { mem::transmute(m) }
             }
         }
 
-        // impl<$S> Into<[$S; ($n * $n)]> for $MatrixN<$S> {
+        // impl<$S> Into<[$S; ($rows * $cols)]> for $MatrixN<$S> {
         //     #[inline]
-        //     fn into(self) -> [[$S; $n]; $n] {
+        //     fn into(self) -> [[$S; $rows]; $cols] {
         //         // TODO: Not sure how to implement this...
         //         unimplemented!()
         //     }
         // }
 
-        impl<$S> AsRef<[$S; ($n * $n)]> for $MatrixN<$S> {
+        impl<$S> AsRef<[$S; ($rows * $cols)]> for $MatrixN<$S> {
             #[inline]
-            fn as_ref(&self) -> &[$S; ($n * $n)] {
+            fn as_ref(&self) -> &[$S; ($rows * $cols)] {
                 unsafe { mem::transmute(self) }
             }
         }
 
-        impl<$S> AsMut<[$S; ($n * $n)]> for $MatrixN<$S> {
+        impl<$S> AsMut<[$S; ($rows * $cols)]> for $MatrixN<$S> {
             #[inline]
-            fn as_mut(&mut self) -> &mut [$S; ($n * $n)] {
+            fn as_mut(&mut self) -> &mut [$S; ($rows * $cols)] {
                 unsafe { mem::transmute(self) }
             }
         }
 
-        // impl<$S> From<[$S; ($n * $n)]> for $MatrixN<$S> {
+        // impl<$S> From<[$S; ($rows * $cols)]> for $MatrixN<$S> {
         //     #[inline]
-        //     fn from(m: [$S; ($n * $n)]) -> $MatrixN<$S> {
+        //     fn from(m: [$S; ($rows * $cols)]) -> $MatrixN<$S> {
         //         // TODO: Not sure how to implement this...
         //         unimplemented!()
         //     }
         // }
 
-        impl<'a, $S> From<&'a [$S; ($n * $n)]> for &'a $MatrixN<$S> {
+        impl<'a, $S> From<&'a [$S; ($rows * $cols)]> for &'a $MatrixN<$S> {
             #[inline]
-            fn from(m: &'a [$S; ($n * $n)]) -> &'a $MatrixN<$S> {
+            fn from(m: &'a [$S; ($rows * $cols)]) -> &'a $MatrixN<$S> {
                 unsafe { mem::transmute(m) }
             }
         }
 
-        impl<'a, $S> From<&'a mut [$S; ($n * $n)]> for &'a mut $MatrixN<$S> {
+        impl<'a, $S> From<&'a mut [$S; ($rows * $cols)]> for &'a mut $MatrixN<$S> {
             #[inline]
-            fn from(m: &'a mut [$S; ($n * $n)]) -> &'a mut $MatrixN<$S> {
+            fn from(m: &'a mut [$S; ($rows * $cols)]) -> &'a mut $MatrixN<$S> {
                 unsafe { mem::transmute(m) }
             }
         }
     }
 }
 
-fixed_array_conversions!(Matrix2<S> { x:0, y:1 }, 2);
-fixed_array_conversions!(Matrix3<S> { x:0, y:1, z:2 }, 3);
-fixed_array_conversions!(Matrix4<S> { x:0, y:1, z:2, w:3 }, 4);
+fixed_array_conversions!(Matrix2<S> { x:0, y:1 }, 2, 2);
+fixed_array_conversions!(Matrix3<S> { x:0, y:1, z:2 }, 3, 3);
+fixed_array_conversions!(Matrix4<S> { x:0, y:1, z:2, w:3 }, 4, 4);
+fixed_array_conversions!(Matrix2x3<S> { x:0, y:1, z:2 }, 2, 3);
+fixed_array_conversions!(Matrix2x4<S> { x:0, y:1, z:2, w:3 }, 2, 4);
+fixed_array_conversions!(Matrix3x2<S> { x:0, y:1 }, 3, 2);
+fixed_array_conversions!(Matrix3x4<S> { x:0, y:1, z:2, w:3 }, 3, 4);
+fixed_array_conversions!(Matrix4x2<S> { x:0, y:1 }, 4, 2);
+fixed_array_conversions!(Matrix4x3<S> { x:0, y:1, z:2 }, 4, 3);
 
 #[cfg(feature = "mint")]
 macro_rules! mint_conversions {
@@ -1571,6 +2660,18 @@ mint_conversions!(Matrix2 { x, y }, ColumnMatrix2);
 mint_conversions!(Matrix3 { x, y, z }, ColumnMatrix3);
 #[cfg(feature = "mint")]
 mint_conversions!(Matrix4 { x, y, z, w }, ColumnMatrix4);
+#[cfg(feature = "mint")]
+mint_conversions!(Matrix2x3 { x, y, z }, ColumnMatrix2x3);
+#[cfg(feature = "mint")]
+mint_conversions!(Matrix2x4 { x, y, z, w }, ColumnMatrix2x4);
+#[cfg(feature = "mint")]
+mint_conversions!(Matrix3x2 { x, y }, ColumnMatrix3x2);
+#[cfg(feature = "mint")]
+mint_conversions!(Matrix3x4 { x, y, z, w }, ColumnMatrix3x4);
+#[cfg(feature = "mint")]
+mint_conversions!(Matrix4x2 { x, y }, ColumnMatrix4x2);
+#[cfg(feature = "mint")]
+mint_conversions!(Matrix4x3 { x, y, z }, ColumnMatrix4x3);
 
 #[cfg(feature = "bytemuck")]
 impl_bytemuck_cast!(Matrix2);
@@ -1578,6 +2679,18 @@ impl_bytemuck_cast!(Matrix2);
 impl_bytemuck_cast!(Matrix3);
 #[cfg(feature = "bytemuck")]
 impl_bytemuck_cast!(Matrix4);
+#[cfg(feature = "bytemuck")]
+impl_bytemuck_cast!(Matrix2x3);
+#[cfg(feature = "bytemuck")]
+impl_bytemuck_cast!(Matrix2x4);
+#[cfg(feature = "bytemuck")]
+impl_bytemuck_cast!(Matrix3x2);
+#[cfg(feature = "bytemuck")]
+impl_bytemuck_cast!(Matrix3x4);
+#[cfg(feature = "bytemuck")]
+impl_bytemuck_cast!(Matrix4x2);
+#[cfg(feature = "bytemuck")]
+impl_bytemuck_cast!(Matrix4x3);
 
 impl<S: BaseNum> From<Matrix2<S>> for Matrix3<S> {
     /// Clone the elements of a 2-dimensional matrix into the top-left corner
@@ -1732,6 +2845,428 @@ where
     }
 }
 
+/// The eigenvalues and eigenvectors of a symmetric matrix, found with cyclic
+/// Jacobi rotations. The eigenvectors are the columns of the second matrix,
+/// in the same order as the eigenvalues, which are not sorted.
+pub(crate) fn jacobi_eigen<S: BaseFloat, M: SquareMatrix<Scalar = S>>(a: M) -> (M::ColumnRow, M) {
+    const MAX_SWEEPS: usize = 32;
+    let n = M::ColumnRow::len();
+    let two: S = cast(2).unwrap();
+    let scale = max_element_scale(&a);
+    let mut a = a / scale;
+    let mut v = M::identity();
+
+    for _ in 0..MAX_SWEEPS {
+        let mut off = S::zero();
+        let mut diagonal = S::zero();
+        for p in 0..n {
+            diagonal += a[p][p] * a[p][p];
+            for q in (p + 1)..n {
+                off += a[p][q] * a[p][q];
+            }
+        }
+        if off <= S::default_epsilon() * S::default_epsilon() * diagonal {
+            break;
+        }
+
+        for p in 0..n {
+            for q in (p + 1)..n {
+                if a[p][q] == S::zero() {
+                    continue;
+                }
+
+                // Zero a[p][q] with a rotation in the p-q plane.
+                let theta = (a[q][q] - a[p][p]) / (two * a[p][q]);
+                let t = theta.signum() / (theta.abs() + (theta * theta + S::one()).sqrt());
+                let c = S::one() / (t * t + S::one()).sqrt();
+                let s = t * c;
+
+                for k in 0..n {
+                    let (akp, akq) = (a[k][p], a[k][q]);
+                    a[k][p] = c * akp - s * akq;
+                    a[k][q] = s * akp + c * akq;
+                }
+                for k in 0..n {
+                    let (apk, aqk) = (a[p][k], a[q][k]);
+                    a[p][k] = c * apk - s * aqk;
+                    a[q][k] = s * apk + c * aqk;
+                }
+                for k in 0..n {
+                    let (vkp, vkq) = (v[p][k], v[q][k]);
+                    v[p][k] = c * vkp - s * vkq;
+                    v[q][k] = s * vkp + c * vkq;
+                }
+            }
+        }
+    }
+
+    (a.diagonal() * scale, v)
+}
+
+fn lu<S: BaseFloat, M: SquareMatrix<Scalar = S>>(m: &M) -> Lu<M> {
+    let n = M::ColumnRow::len();
+    let mut l = M::identity();
+    let mut u = *m;
+    let mut p = M::identity();
+
+    for k in 0..n {
+        let mut pivot = k;
+        for r in (k + 1)..n {
+            if u[k][r].abs() > u[k][pivot].abs() {
+                pivot = r;
+            }
+        }
+        if pivot != k {
+            u.swap_rows(k, pivot);
+            p.swap_rows(k, pivot);
+            for c in 0..k {
+                l.swap_elements((c, k), (c, pivot));
+            }
+        }
+        if u[k][k] == S::zero() {
+            continue;
+        }
+
+        for r in (k + 1)..n {
+            let factor = u[k][r] / u[k][k];
+            l[k][r] = factor;
+            u[k][r] = S::zero();
+            for c in (k + 1)..n {
+                let x = factor * u[c][k];
+                u[c][r] -= x;
+            }
+        }
+    }
+
+    Lu { l, u, p }
+}
+
+fn qr<S: BaseFloat, M: SquareMatrix<Scalar = S>>(m: &M) -> Qr<M> {
+    let n = M::ColumnRow::len();
+    let two: S = cast(2).unwrap();
+    let mut q = M::identity();
+    let mut r = *m;
+
+    for k in 0..(n - 1) {
+        // Reflect column `k` onto the `k`th axis, choosing the direction that
+        // avoids cancellation.
+        let norm = (k..n)
+            .fold(S::zero(), |sum, i| sum + r[k][i] * r[k][i])
+            .sqrt();
+        if norm == S::zero() {
+            continue;
+        }
+        let alpha = if r[k][k] > S::zero() { -norm } else { norm };
+
+        let mut v = M::ColumnRow::from_value(S::zero());
+        for i in k..n {
+            v[i] = r[k][i];
+        }
+        v[k] -= alpha;
+        let vv = (k..n).fold(S::zero(), |sum, i| sum + v[i] * v[i]);
+
+        // r = H * r and q = q * H, with H = I - 2 * v * v^T / (v^T * v).
+        for c in k..n {
+            let f = two * (k..n).fold(S::zero(), |sum, i| sum + v[i] * r[c][i]) / vv;
+            for i in k..n {
+                r[c][i] -= f * v[i];
+            }
+        }
+        for row in 0..n {
+            let f = two * (k..n).fold(S::zero(), |sum, i| sum + q[i][row] * v[i]) / vv;
+            for j in k..n {
+                q[j][row] -= f * v[j];
+            }
+        }
+        for i in (k + 1)..n {
+            r[k][i] = S::zero();
+        }
+    }
+
+    Qr { q, r }
+}
+
+/// Solve `u * x == y` for an upper triangular `u`. Returns `None` if a
+/// diagonal entry is lost in the rounding error of the matrix, so that the
+/// result does not depend on the scale of `u`.
+fn back_substitute<S: BaseFloat, M: SquareMatrix<Scalar = S>>(
+    u: &M,
+    mut y: M::ColumnRow,
+) -> Option<M::ColumnRow> {
+    let n = M::ColumnRow::len();
+    let tolerance = S::epsilon() * norm_1(u);
+    for r in (0..n).rev() {
+        if u[r][r].abs() <= tolerance {
+            return None;
+        }
+        for c in (r + 1)..n {
+            let uy = u[c][r] * y[c];
+            y[r] -= uy;
+        }
+        y[r] /= u[r][r];
+    }
+    Some(y)
+}
+
+fn condition_number<S: BaseFloat, M: SquareMatrix<Scalar = S>>(m: &M) -> S {
+    let n = M::ColumnRow::len();
+    let lu = lu(m);
+    let mut inverse = M::identity();
+    for c in 0..n {
+        match lu.solve(inverse[c]) {
+            Some(x) => inverse[c] = x,
+            None => return S::infinity(),
+        }
+    }
+    norm_1(m) * norm_1(&inverse)
+}
+
+/// The singular value decomposition `m == U * diag(σ) * Vᵀ`, using one-sided
+/// Jacobi rotations: the columns of `m` are rotated until they are
+/// orthogonal, at which point they are `σᵢ * uᵢ` and the accumulated
+/// rotations are `V`. Returns `m * V / scale`, `V` and `scale`, with the
+/// columns sorted from largest to smallest singular value. The columns are
+/// left divided by the largest element of `m`, so that their magnitudes can
+/// be computed without overflow.
+fn svd<S: BaseFloat, M: SquareMatrix<Scalar = S>>(m: &M) -> (M, M, S)
+where
+    M::ColumnRow: InnerSpace,
+{
+    const MAX_SWEEPS: usize = 32;
+    let n = M::ColumnRow::len();
+    let two: S = cast(2).unwrap();
+    let scale = max_element_scale(m);
+    let mut a = *m / scale;
+    let mut v = M::identity();
+
+    for _ in 0..MAX_SWEEPS {
+        let mut rotated = false;
+        for p in 0..n {
+            for q in (p + 1)..n {
+                let alpha = a[p].magnitude2();
+                let beta = a[q].magnitude2();
+                let gamma = a[p].dot(a[q]);
+                if gamma.abs() <= S::epsilon() * (alpha * beta).sqrt() {
+                    continue;
+                }
+                rotated = true;
+
+                let zeta = (beta - alpha) / (two * gamma);
+                let t = zeta.signum() / (zeta.abs() + (S::one() + zeta * zeta).sqrt());
+                let c = S::one() / (S::one() + t * t).sqrt();
+                let s = c * t;
+                let (ap, aq) = (a[p], a[q]);
+                a[p] = ap * c - aq * s;
+                a[q] = ap * s + aq * c;
+                let (vp, vq) = (v[p], v[q]);
+                v[p] = vp * c - vq * s;
+                v[q] = vp * s + vq * c;
+            }
+        }
+        if !rotated {
+            break;
+        }
+    }
+
+    for i in 0..n {
+        let mut max = i;
+        for j in (i + 1)..n {
+            if a[j].magnitude2() > a[max].magnitude2() {
+                max = j;
+            }
+        }
+        a.swap_columns(i, max);
+        v.swap_columns(i, max);
+    }
+
+    (a, v, scale)
+}
+
+/// The pseudo-inverse `V * Σ⁺ * Uᵀ`, from the singular value decomposition.
+fn pseudo_inverse<S: BaseFloat, M: SquareMatrix<Scalar = S>>(m: &M) -> M
+where
+    M::ColumnRow: InnerSpace,
+{
+    let n = M::ColumnRow::len();
+    let (a, v, scale) = svd(m);
+    let tolerance = cast::<_, S>(n).unwrap() * S::epsilon() * a[0].magnitude();
+    let mut result = M::zero();
+    for i in 0..n {
+        // Each term is `vᵢ * uᵢᵀ / σᵢ`, with `a[i] == σᵢ * uᵢ / scale`.
+        let sigma2 = a[i].magnitude2();
+        if sigma2.sqrt() <= tolerance {
+            break;
+        }
+        for c in 0..n {
+            let column = v[i] * (a[i][c] / sigma2 / scale);
+            result[c] = result[c] + column;
+        }
+    }
+    result
+}
+
+/// Is `x` lost in the rounding error of floating point operations on values
+/// of the size of `scale`, allowing a few units in the last place. The
+/// comparison is purely relative, so it does not depend on the overall scale
+/// of the values.
+fn is_negligible<S: BaseFloat>(x: S, scale: S) -> bool {
+    let ulps: S = cast(4).unwrap();
+    x.abs() <= ulps * S::epsilon() * scale
+}
+
+/// The largest absolute element of a matrix, or one if that is zero or not
+/// finite. Dividing by this keeps the sums of squares in the Jacobi methods
+/// from overflowing or underflowing.
+fn max_element_scale<S: BaseFloat, M: SquareMatrix<Scalar = S>>(m: &M) -> S {
+    let n = M::ColumnRow::len();
+    let max = (0..n)
+        .map(|c| (0..n).fold(S::zero(), |max, r| max.max(m[c][r].abs())))
+        .fold(S::zero(), S::max);
+    if max > S::zero() && max.is_finite() {
+        max
+    } else {
+        S::one()
+    }
+}
+
+/// The 1-norm of a matrix, its largest absolute column sum.
+fn norm_1<S: BaseFloat, M: SquareMatrix<Scalar = S>>(m: &M) -> S {
+    let n = M::ColumnRow::len();
+    (0..n)
+        .map(|c| (0..n).fold(S::zero(), |sum, r| sum + m[c][r].abs()))
+        .fold(S::zero(), S::max)
+}
+
+/// The matrix exponential, using the [6/6] Padé approximant with scaling
+/// and squaring, as described by Golub and Van Loan.
+fn exp<S: BaseFloat, M: SquareMatrix<Scalar = S>>(m: &M) -> M {
+    let half: S = cast(0.5f64).unwrap();
+    let coefficients: [S; 6] = [
+        half,
+        cast(5.0f64 / 44.0).unwrap(),
+        cast(1.0f64 / 66.0).unwrap(),
+        cast(1.0f64 / 792.0).unwrap(),
+        cast(1.0f64 / 15_840.0).unwrap(),
+        cast(1.0f64 / 665_280.0).unwrap(),
+    ];
+
+    // Like the scalar exponential, propagate non-finite input to the result
+    // rather than silently skipping the scaling.
+    let n = M::ColumnRow::len();
+    if !(0..n).all(|c| (0..n).all(|r| m[c][r].is_finite())) {
+        let mut result = *m;
+        for c in 0..n {
+            for r in 0..n {
+                result[c][r] = S::nan();
+            }
+        }
+        return result;
+    }
+
+    // Scale the matrix so that its norm is at most 1/2, where the
+    // approximant is accurate to double precision.
+    let norm = norm_1(m);
+    let squarings: u32 = if norm > half {
+        cast((norm / half).log2().ceil()).unwrap()
+    } else {
+        0
+    };
+    let x = *m / (S::one() + S::one()).powi(squarings as i32);
+
+    let mut numerator = M::identity();
+    let mut denominator = M::identity();
+    let mut power = M::identity();
+    for (k, &c) in coefficients.iter().enumerate() {
+        power = power * x;
+        let term = power * c;
+        numerator = numerator + term;
+        denominator = if k % 2 == 0 {
+            denominator - term
+        } else {
+            denominator + term
+        };
+    }
+
+    // The eigenvalues of the denominator are within 1/2 of 1, so it is
+    // always invertible.
+    let lu = lu(&denominator);
+    let mut result = numerator;
+    for c in 0..n {
+        result[c] = lu
+            .solve(numerator[c])
+            .expect("the Padé denominator is invertible");
+    }
+    for _ in 0..squarings {
+        result = result * result;
+    }
+    result
+}
+
+/// The principal matrix logarithm, using inverse scaling and squaring: square
+/// roots are taken until the matrix is close to the identity, and then the
+/// logarithm is evaluated with Gauss-Legendre quadrature of
+/// `log(I + X) = integral from 0 to 1 of X * (I + t * X)^-1 dt`, which is
+/// equivalent to a [7/7] Padé approximant.
+fn ln<S: BaseFloat, M: SquareMatrix<Scalar = S>>(m: &M) -> Option<M> {
+    const MAX_SQUARE_ROOTS: u32 = 64;
+    // The nodes and weights of the 7 point Gauss-Legendre rule on [-1, 1].
+    const GAUSS_LEGENDRE: [(f64, f64); 7] = [
+        (-0.949_107_912_342_758_5, 0.129_484_966_168_869_7),
+        (-0.741_531_185_599_394_4, 0.279_705_391_489_276_7),
+        (-0.405_845_151_377_397_2, 0.381_830_050_505_118_9),
+        (0.0, 0.417_959_183_673_469_4),
+        (0.405_845_151_377_397_2, 0.381_830_050_505_118_9),
+        (0.741_531_185_599_394_4, 0.279_705_391_489_276_7),
+        (0.949_107_912_342_758_5, 0.129_484_966_168_869_7),
+    ];
+
+    let n = M::ColumnRow::len();
+    if !(0..n).all(|c| (0..n).all(|r| m[c][r].is_finite())) {
+        return None;
+    }
+
+    let half: S = cast(0.5f64).unwrap();
+    let mut a = *m;
+    let mut roots = 0;
+    while norm_1(&(a - M::identity())) > half * half {
+        if roots == MAX_SQUARE_ROOTS {
+            return None;
+        }
+        a = sqrt(&a)?;
+        roots += 1;
+    }
+
+    let x = a - M::identity();
+    let mut log = M::zero();
+    for &(node, weight) in &GAUSS_LEGENDRE {
+        let t: S = cast((node + 1.0) * 0.5).unwrap();
+        let w: S = cast(weight * 0.5).unwrap();
+        log = log + x * (M::identity() + x * t).invert()? * w;
+    }
+    Some(log * (S::one() + S::one()).powi(roots as i32))
+}
+
+/// The principal matrix square root, using the Denman-Beavers iteration.
+fn sqrt<S: BaseFloat, M: SquareMatrix<Scalar = S>>(m: &M) -> Option<M> {
+    const MAX_ITERATIONS: usize = 64;
+
+    let half: S = cast(0.5f64).unwrap();
+    let tolerance = S::default_epsilon() * cast(16).unwrap();
+    let mut y = *m;
+    let mut z = M::identity();
+    for _ in 0..MAX_ITERATIONS {
+        let (y_inv, z_inv) = (y.invert()?, z.invert()?);
+        let next = (y + z_inv) * half;
+        z = (z + y_inv) * half;
+        let step = norm_1(&(next - y));
+        y = next;
+        if step <= tolerance * norm_1(&y) {
+            return Some(y);
+        }
+    }
+    None
+}
+
 // Sub procedure for SIMD when dealing with determinant and inversion
 #[inline]
 unsafe fn det_sub_proc_unsafe<S: BaseFloat>(
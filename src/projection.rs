@@ -22,9 +22,178 @@ use angle::Rad;
 use matrix::Matrix4;
 use num::BaseFloat;
 
+/// The range that clip-space depth values are mapped to after perspective
+/// division.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum DepthRange {
+    /// Depth values range over `[-1, 1]`, as in OpenGL.
+    NegativeOneToOne,
+    /// Depth values range over `[0, 1]`, as in Vulkan, Direct3D, Metal and
+    /// WebGPU.
+    ZeroToOne,
+}
+
+/// The direction that the Y axis points in normalized device coordinates.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum YAxis {
+    /// `y = 1` is at the top of the viewport, as in OpenGL, Direct3D, Metal
+    /// and WebGPU.
+    Up,
+    /// `y = 1` is at the bottom of the viewport, as in Vulkan.
+    Down,
+}
+
+/// The handedness of the view space that a projection is applied to.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Handedness {
+    /// A right-handed view space, in which the camera looks down the negative
+    /// Z axis.
+    Right,
+    /// A left-handed view space, in which the camera looks down the positive
+    /// Z axis.
+    Left,
+}
+
+/// The conventions used to map view space to clip space.
+///
+/// The default is `ClipSpace::OPENGL`, which is also what the `perspective`,
+/// `frustum`, `ortho` and `planar` functions produce. Their `_with` variants
+/// take the convention as an extra argument.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ClipSpace {
+    /// The range of normalized device depth values.
+    pub depth: DepthRange,
+    /// The direction of the Y axis in normalized device coordinates.
+    pub y_axis: YAxis,
+    /// The handedness of view space.
+    pub handedness: Handedness,
+}
+
+impl ClipSpace {
+    /// Right-handed view space, Y-up and depth in `[-1, 1]`.
+    pub const OPENGL: ClipSpace = ClipSpace {
+        depth: DepthRange::NegativeOneToOne,
+        y_axis: YAxis::Up,
+        handedness: Handedness::Right,
+    };
+
+    /// Right-handed view space, Y-down and depth in `[0, 1]`.
+    pub const VULKAN: ClipSpace = ClipSpace {
+        depth: DepthRange::ZeroToOne,
+        y_axis: YAxis::Down,
+        handedness: Handedness::Right,
+    };
+
+    /// Left-handed view space, Y-up and depth in `[0, 1]`, matching the
+    /// traditional `D3DXMatrix*LH` functions.
+    pub const DIRECT3D: ClipSpace = ClipSpace {
+        depth: DepthRange::ZeroToOne,
+        y_axis: YAxis::Up,
+        handedness: Handedness::Left,
+    };
+
+    /// Right-handed view space, Y-up and depth in `[0, 1]`, as used by WebGPU
+    /// and Metal.
+    pub const WGPU: ClipSpace = ClipSpace {
+        depth: DepthRange::ZeroToOne,
+        y_axis: YAxis::Up,
+        handedness: Handedness::Right,
+    };
+
+    /// Adapt a right-handed, Y-up projection matrix that already maps depth
+    /// to `self.depth` to the handedness and Y direction of this convention.
+    fn orient<S: BaseFloat>(self, mut m: Matrix4<S>) -> Matrix4<S> {
+        if self.handedness == Handedness::Left {
+            m.z = -m.z;
+        }
+        if self.y_axis == YAxis::Down {
+            m.x.y = -m.x.y;
+            m.y.y = -m.y.y;
+            m.z.y = -m.z.y;
+            m.w.y = -m.w.y;
+        }
+        m
+    }
+}
+
+impl Default for ClipSpace {
+    #[inline]
+    fn default() -> ClipSpace {
+        ClipSpace::OPENGL
+    }
+}
+
+/// How a perspective projection maps view-space depth to normalized device
+/// depth.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum PerspectiveMode {
+    /// The near plane is mapped to the lower end of the clip-space depth
+    /// range and the far plane to 1.
+    Standard,
+    /// Like `Standard`, but with the far plane placed at infinity. The `far`
+    /// distance of the projection is ignored.
+    InfiniteFar,
+    /// The near plane is mapped to 1 and the far plane to 0, regardless of the
+    /// clip-space depth range. Combined with a floating point depth buffer,
+    /// this distributes depth precision much more evenly than `Standard`.
+    ReversedZ,
+    /// Like `ReversedZ`, but with the far plane placed at infinity. The `far`
+    /// distance of the projection is ignored.
+    ReversedZInfiniteFar,
+}
+
+impl PerspectiveMode {
+    /// Returns `true` if the far plane is placed at infinity.
+    #[inline]
+    pub fn is_infinite(self) -> bool {
+        match self {
+            PerspectiveMode::InfiniteFar | PerspectiveMode::ReversedZInfiniteFar => true,
+            PerspectiveMode::Standard | PerspectiveMode::ReversedZ => false,
+        }
+    }
+
+    /// Returns `true` if the near plane is mapped to a greater depth than the
+    /// far plane.
+    #[inline]
+    pub fn is_reversed(self) -> bool {
+        match self {
+            PerspectiveMode::ReversedZ | PerspectiveMode::ReversedZInfiniteFar => true,
+            PerspectiveMode::Standard | PerspectiveMode::InfiniteFar => false,
+        }
+    }
+
+    /// The entries of a right-handed perspective matrix that map view-space
+    /// depth to clip-space depth, as the `(c2r2, c3r2)` pair. The `c2r3`
+    /// entry is always `-1`.
+    fn depth_terms<S: BaseFloat>(self, near: S, far: S, depth: DepthRange) -> (S, S) {
+        let two: S = cast(2).unwrap();
+        match (self, depth) {
+            (PerspectiveMode::Standard, DepthRange::NegativeOneToOne) => (
+                (far + near) / (near - far),
+                (two * far * near) / (near - far),
+            ),
+            (PerspectiveMode::Standard, DepthRange::ZeroToOne) => {
+                (far / (near - far), (far * near) / (near - far))
+            }
+            (PerspectiveMode::InfiniteFar, DepthRange::NegativeOneToOne) => {
+                (-S::one(), -two * near)
+            }
+            (PerspectiveMode::InfiniteFar, DepthRange::ZeroToOne) => (-S::one(), -near),
+            (PerspectiveMode::ReversedZ, _) => (near / (far - near), (far * near) / (far - near)),
+            (PerspectiveMode::ReversedZInfiniteFar, _) => (S::zero(), near),
+        }
+    }
+}
+
 /// Create a perspective projection matrix.
 ///
 /// This is the equivalent to the [`gluPerspective`] function.
+/// For other clip-space conventions, use [`perspective_with`].
 ///
 /// [`gluPerspective`]: https://www.opengl.org/sdk/docs/man2/xhtml/gluPerspective.xml
 pub fn perspective<S: BaseFloat, A: Into<Rad<S>>>(
@@ -42,9 +211,28 @@ pub fn perspective<S: BaseFloat, A: Into<Rad<S>>>(
     .into()
 }
 
+/// Create a perspective projection matrix for the `clip` conventions, with
+/// the same parameters as [`perspective`].
+pub fn perspective_with<S: BaseFloat, A: Into<Rad<S>>>(
+    fovy: A,
+    aspect: S,
+    near: S,
+    far: S,
+    clip: ClipSpace,
+) -> Matrix4<S> {
+    PerspectiveFov {
+        fovy: fovy.into(),
+        aspect,
+        near,
+        far,
+    }
+    .to_matrix_with(PerspectiveMode::Standard, clip)
+}
+
 /// Create a perspective matrix from a view frustum.
 ///
 /// This is the equivalent of the now deprecated [`glFrustum`] function.
+/// For other clip-space conventions, use [`frustum_with`].
 ///
 /// [`glFrustum`]: http://www.opengl.org/sdk/docs/man2/xhtml/glFrustum.xml
 pub fn frustum<S: BaseFloat>(left: S, right: S, bottom: S, top: S, near: S, far: S) -> Matrix4<S> {
@@ -59,9 +247,32 @@ pub fn frustum<S: BaseFloat>(left: S, right: S, bottom: S, top: S, near: S, far:
     .into()
 }
 
+/// Create a perspective matrix from a view frustum for the `clip`
+/// conventions, with the same parameters as [`frustum`].
+pub fn frustum_with<S: BaseFloat>(
+    left: S,
+    right: S,
+    bottom: S,
+    top: S,
+    near: S,
+    far: S,
+    clip: ClipSpace,
+) -> Matrix4<S> {
+    Perspective {
+        left,
+        right,
+        bottom,
+        top,
+        near,
+        far,
+    }
+    .to_matrix_with(PerspectiveMode::Standard, clip)
+}
+
 /// Create an orthographic projection matrix.
 ///
 /// This is the equivalent of the now deprecated [`glOrtho`] function.
+/// For other clip-space conventions, use [`ortho_with`].
 ///
 /// [`glOrtho`]: http://www.opengl.org/sdk/docs/man2/xhtml/glOrtho.xml
 pub fn ortho<S: BaseFloat>(left: S, right: S, bottom: S, top: S, near: S, far: S) -> Matrix4<S> {
@@ -76,11 +287,35 @@ pub fn ortho<S: BaseFloat>(left: S, right: S, bottom: S, top: S, near: S, far: S
     .into()
 }
 
+/// Create an orthographic projection matrix for the `clip` conventions, with
+/// the same parameters as [`ortho`].
+pub fn ortho_with<S: BaseFloat>(
+    left: S,
+    right: S,
+    bottom: S,
+    top: S,
+    near: S,
+    far: S,
+    clip: ClipSpace,
+) -> Matrix4<S> {
+    Ortho {
+        left,
+        right,
+        bottom,
+        top,
+        near,
+        far,
+    }
+    .to_matrix_with(clip)
+}
+
 /// Create a planar projection matrix, which can be either perspective or orthographic.
 ///
 /// The projection frustum is always `height` units high at the origin along the view direction,
 /// making the focal point located at `(0.0, 0.0, cot(fovy / 2.0)) * height / 2.0`. Unlike
 /// a standard perspective projection, this allows `fovy` to be zero or negative.
+///
+/// For other clip-space conventions, use [`planar_with`].
 pub fn planar<S: BaseFloat, A: Into<Rad<S>>>(
     fovy: A,
     aspect: S,
@@ -98,6 +333,26 @@ pub fn planar<S: BaseFloat, A: Into<Rad<S>>>(
     .into()
 }
 
+/// Create a planar projection matrix for the `clip` conventions, with the
+/// same parameters as [`planar`].
+pub fn planar_with<S: BaseFloat, A: Into<Rad<S>>>(
+    fovy: A,
+    aspect: S,
+    height: S,
+    near: S,
+    far: S,
+    clip: ClipSpace,
+) -> Matrix4<S> {
+    PlanarFov {
+        fovy: fovy.into(),
+        aspect,
+        height,
+        near,
+        far,
+    }
+    .to_matrix_with(clip)
+}
+
 /// A perspective projection based on a vertical field-of-view angle.
 #[derive(Copy, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "rustc-serialize", derive(RustcEncodable, RustcDecodable))]
@@ -129,6 +384,21 @@ impl<S: BaseFloat> PerspectiveFov<S> {
 
 impl<S: BaseFloat> From<PerspectiveFov<S>> for Matrix4<S> {
     fn from(persp: PerspectiveFov<S>) -> Matrix4<S> {
+        persp.to_matrix(PerspectiveMode::Standard)
+    }
+}
+
+impl<S: BaseFloat> PerspectiveFov<S> {
+    /// Create the projection matrix, mapping depth as described by `mode`.
+    pub fn to_matrix(&self, mode: PerspectiveMode) -> Matrix4<S> {
+        self.to_matrix_with(mode, ClipSpace::OPENGL)
+    }
+
+    /// Create the projection matrix for the `clip` conventions, mapping depth
+    /// as described by `mode`.
+    pub fn to_matrix_with(&self, mode: PerspectiveMode, clip: ClipSpace) -> Matrix4<S> {
+        let persp = self;
+
         assert!(
             persp.fovy > Rad::zero(),
             "The vertical field of view cannot be below zero, found: {:?}",
@@ -150,20 +420,23 @@ impl<S: BaseFloat> From<PerspectiveFov<S>> for Matrix4<S> {
             "The near plane distance cannot be below zero, found: {:?}",
             persp.near
         );
-        assert!(
-            persp.far > S::zero(),
-            "The far plane distance cannot be below zero, found: {:?}",
-            persp.far
-        );
-        assert!(
-            abs_diff_ne!(persp.far, persp.near),
-            "The far plane and near plane are too close, found: far: {:?}, near: {:?}",
-            persp.far,
-            persp.near
-        );
+        if !mode.is_infinite() {
+            assert!(
+                persp.far > S::zero(),
+                "The far plane distance cannot be below zero, found: {:?}",
+                persp.far
+            );
+            assert!(
+                abs_diff_ne!(persp.far, persp.near),
+                "The far plane and near plane are too close, found: far: {:?}, near: {:?}",
+                persp.far,
+                persp.near
+            );
+        }
 
         let two: S = cast(2).unwrap();
         let f = Rad::cot(persp.fovy / two);
+        let (c2r2, c3r2) = mode.depth_terms(persp.near, persp.far, clip.depth);
 
         let c0r0 = f / persp.aspect;
         let c0r1 = S::zero();
@@ -177,21 +450,21 @@ impl<S: BaseFloat> From<PerspectiveFov<S>> for Matrix4<S> {
 
         let c2r0 = S::zero();
         let c2r1 = S::zero();
-        let c2r2 = (persp.far + persp.near) / (persp.near - persp.far);
         let c2r3 = -S::one();
 
         let c3r0 = S::zero();
         let c3r1 = S::zero();
-        let c3r2 = (two * persp.far * persp.near) / (persp.near - persp.far);
         let c3r3 = S::zero();
 
         #[cfg_attr(rustfmt, rustfmt_skip)]
-        Matrix4::new(
+        let m = Matrix4::new(
             c0r0, c0r1, c0r2, c0r3,
             c1r0, c1r1, c1r2, c1r3,
             c2r0, c2r1, c2r2, c2r3,
             c3r0, c3r1, c3r2, c3r3,
-        )
+        );
+
+        clip.orient(m)
     }
 }
 
@@ -209,6 +482,21 @@ pub struct Perspective<S> {
 
 impl<S: BaseFloat> From<Perspective<S>> for Matrix4<S> {
     fn from(persp: Perspective<S>) -> Matrix4<S> {
+        persp.to_matrix(PerspectiveMode::Standard)
+    }
+}
+
+impl<S: BaseFloat> Perspective<S> {
+    /// Create the projection matrix, mapping depth as described by `mode`.
+    pub fn to_matrix(&self, mode: PerspectiveMode) -> Matrix4<S> {
+        self.to_matrix_with(mode, ClipSpace::OPENGL)
+    }
+
+    /// Create the projection matrix for the `clip` conventions, mapping depth
+    /// as described by `mode`.
+    pub fn to_matrix_with(&self, mode: PerspectiveMode, clip: ClipSpace) -> Matrix4<S> {
+        let persp = self;
+
         assert!(
             persp.left <= persp.right,
             "`left` cannot be greater than `right`, found: left: {:?} right: {:?}",
@@ -221,14 +509,17 @@ impl<S: BaseFloat> From<Perspective<S>> for Matrix4<S> {
             persp.bottom,
             persp.top
         );
-        assert!(
-            persp.near <= persp.far,
-            "`near` cannot be greater than `far`, found: near: {:?} far: {:?}",
-            persp.near,
-            persp.far
-        );
+        if !mode.is_infinite() {
+            assert!(
+                persp.near <= persp.far,
+                "`near` cannot be greater than `far`, found: near: {:?} far: {:?}",
+                persp.near,
+                persp.far
+            );
+        }
 
         let two: S = cast(2i8).unwrap();
+        let (c2r2, c3r2) = mode.depth_terms(persp.near, persp.far, clip.depth);
 
         let c0r0 = (two * persp.near) / (persp.right - persp.left);
         let c0r1 = S::zero();
@@ -242,21 +533,21 @@ impl<S: BaseFloat> From<Perspective<S>> for Matrix4<S> {
 
         let c2r0 = (persp.right + persp.left) / (persp.right - persp.left);
         let c2r1 = (persp.top + persp.bottom) / (persp.top - persp.bottom);
-        let c2r2 = -(persp.far + persp.near) / (persp.far - persp.near);
         let c2r3 = -S::one();
 
         let c3r0 = S::zero();
         let c3r1 = S::zero();
-        let c3r2 = -(two * persp.far * persp.near) / (persp.far - persp.near);
         let c3r3 = S::zero();
 
         #[cfg_attr(rustfmt, rustfmt_skip)]
-        Matrix4::new(
+        let m = Matrix4::new(
             c0r0, c0r1, c0r2, c0r3,
             c1r0, c1r1, c1r2, c1r3,
             c2r0, c2r1, c2r2, c2r3,
             c3r0, c3r1, c3r2, c3r3,
-        )
+        );
+
+        clip.orient(m)
     }
 }
 
@@ -274,6 +565,14 @@ pub struct Ortho<S> {
 
 impl<S: BaseFloat> From<Ortho<S>> for Matrix4<S> {
     fn from(ortho: Ortho<S>) -> Matrix4<S> {
+        ortho.to_matrix_with(ClipSpace::OPENGL)
+    }
+}
+
+impl<S: BaseFloat> Ortho<S> {
+    /// Create the projection matrix for the `clip` conventions.
+    pub fn to_matrix_with(&self, clip: ClipSpace) -> Matrix4<S> {
+        let ortho = self;
         let two: S = cast(2).unwrap();
 
         let c0r0 = two / (ortho.right - ortho.left);
@@ -288,21 +587,29 @@ impl<S: BaseFloat> From<Ortho<S>> for Matrix4<S> {
 
         let c2r0 = S::zero();
         let c2r1 = S::zero();
-        let c2r2 = -two / (ortho.far - ortho.near);
+        let c2r2 = match clip.depth {
+            DepthRange::NegativeOneToOne => -two / (ortho.far - ortho.near),
+            DepthRange::ZeroToOne => -S::one() / (ortho.far - ortho.near),
+        };
         let c2r3 = S::zero();
 
         let c3r0 = -(ortho.right + ortho.left) / (ortho.right - ortho.left);
         let c3r1 = -(ortho.top + ortho.bottom) / (ortho.top - ortho.bottom);
-        let c3r2 = -(ortho.far + ortho.near) / (ortho.far - ortho.near);
+        let c3r2 = match clip.depth {
+            DepthRange::NegativeOneToOne => -(ortho.far + ortho.near) / (ortho.far - ortho.near),
+            DepthRange::ZeroToOne => -ortho.near / (ortho.far - ortho.near),
+        };
         let c3r3 = S::one();
 
         #[cfg_attr(rustfmt, rustfmt_skip)]
-        Matrix4::new(
+        let m = Matrix4::new(
             c0r0, c0r1, c0r2, c0r3,
             c1r0, c1r1, c1r2, c1r3,
             c2r0, c2r1, c2r2, c2r3,
             c3r0, c3r1, c3r2, c3r3,
-        )
+        );
+
+        clip.orient(m)
     }
 }
 
@@ -319,6 +626,14 @@ pub struct PlanarFov<S> {
 
 impl<S: BaseFloat> From<PlanarFov<S>> for Matrix4<S> {
     fn from(persp: PlanarFov<S>) -> Matrix4<S> {
+        persp.to_matrix_with(ClipSpace::OPENGL)
+    }
+}
+
+impl<S: BaseFloat> PlanarFov<S> {
+    /// Create the projection matrix for the `clip` conventions.
+    pub fn to_matrix_with(&self, clip: ClipSpace) -> Matrix4<S> {
+        let persp = self;
         assert!(
             persp.fovy > -Rad::turn_div_2(),
             "The vertical field of view cannot be less than a negative half turn, found: {:?}",
@@ -380,12 +695,20 @@ impl<S: BaseFloat> From<PlanarFov<S>> for Matrix4<S> {
             / (persp.near - persp.far);
         let c3r3 = S::one();
 
+        // Remap clip-space depth from `[-w, w]` to `[0, w]`.
+        let (c2r2, c3r2) = match clip.depth {
+            DepthRange::NegativeOneToOne => (c2r2, c3r2),
+            DepthRange::ZeroToOne => ((c2r2 + c2r3) / two, (c3r2 + c3r3) / two),
+        };
+
         #[cfg_attr(rustfmt, rustfmt_skip)]
-        Matrix4::new(
+        let m = Matrix4::new(
             c0r0, c0r1, c0r2, c0r3,
             c1r0, c1r1, c1r2, c1r3,
             c2r0, c2r1, c2r2, c2r3,
             c3r0, c3r1, c3r2, c3r3,
-        )
+        );
+
+        clip.orient(m)
     }
 }
@@ -27,13 +27,13 @@ use structure::*;
 
 use angle::Rad;
 use approx;
-use euler::Euler;
-use matrix::{Matrix3, Matrix4};
+use euler::{Euler, EulerAngles, EulerFrame};
+use matrix::{jacobi_eigen, Matrix3, Matrix4};
 use num::{BaseFloat, BaseNum};
 use point::Point3;
 use quaternion;
 use rotation::{Basis3, Rotation, Rotation3};
-use vector::Vector3;
+use vector::{Vector3, Vector4};
 
 #[cfg(feature = "mint")]
 use mint;
@@ -157,9 +157,297 @@ impl<S: BaseFloat> Quaternion<S> {
         }
     }
 
+    /// The exponential of the quaternion.
+    ///
+    /// For a quaternion with a zero scalar part, `(0, v)`, this is the unit
+    /// quaternion that rotates by `2 * v.magnitude()` radians about `v`, which
+    /// makes it useful for integrating an angular velocity over a timestep.
+    pub fn exp(self) -> Quaternion<S> {
+        let angle = self.v.magnitude();
+        let (sin, cos) = angle.sin_cos();
+        let scale = self.s.exp();
+
+        // sin(angle) / angle, using a Taylor series close to zero where the
+        // division would be inaccurate.
+        let sinc = if angle < S::default_epsilon().sqrt() {
+            let six: S = cast(6).unwrap();
+            S::one() - angle * angle / six
+        } else {
+            sin / angle
+        };
+
+        Quaternion::from_sv(cos * scale, self.v * (sinc * scale))
+    }
+
+    /// The natural logarithm of the quaternion, the inverse of `exp`.
+    ///
+    /// For a unit quaternion this is `(0, axis * angle / 2)`, where `axis`
+    /// and `angle` describe the rotation. The logarithm of a negative real
+    /// quaternion is not unique; its vector part is chosen to lie along the
+    /// X axis.
+    pub fn ln(self) -> Quaternion<S> {
+        let v_magnitude = self.v.magnitude();
+        let magnitude = (self.s * self.s + v_magnitude * v_magnitude).sqrt();
+        let angle = v_magnitude.atan2(self.s);
+
+        let v = if v_magnitude < S::default_epsilon().sqrt() {
+            if self.s > S::zero() {
+                // angle / v_magnitude tends to 1 / s close to the real axis.
+                self.v / self.s
+            } else if ulps_eq!(v_magnitude, &S::zero()) {
+                Vector3::unit_x() * angle
+            } else {
+                self.v * (angle / v_magnitude)
+            }
+        } else {
+            self.v * (angle / v_magnitude)
+        };
+
+        Quaternion::from_sv(magnitude.ln(), v)
+    }
+
+    /// Raise the quaternion to a real power.
+    ///
+    /// For a unit quaternion, this scales the angle of the rotation it
+    /// represents by `exponent`. Like `ln`, the result is only unique when
+    /// the quaternion is not a negative real number.
+    #[inline]
+    pub fn powf(self, exponent: S) -> Quaternion<S> {
+        (self.ln() * exponent).exp()
+    }
+
+    /// Compute the control point for `self` to use with `squad`, given the
+    /// keyframes before and after it in a sequence of unit quaternions.
+    ///
+    /// The neighbouring keyframes are moved to the same hemisphere as `self`
+    /// first, so the sequence only needs to be hemisphere-consistent when it
+    /// is interpolated, not when the control points are computed.
+    pub fn squad_control_point(
+        self,
+        mut prev: Quaternion<S>,
+        mut next: Quaternion<S>,
+    ) -> Quaternion<S> {
+        if self.dot(prev) < S::zero() {
+            prev = -prev;
+        }
+        if self.dot(next) < S::zero() {
+            next = -next;
+        }
+
+        let inverse = self.conjugate();
+        let quarter: S = cast(0.25f64).unwrap();
+        let tangent = ((inverse * next).ln() + (inverse * prev).ln()) * -quarter;
+
+        (self * tangent.exp()).normalize()
+    }
+
+    /// Spherical cubic interpolation between `self` and `other`, by
+    /// `amount`.
+    ///
+    /// `a` and `b` are the control points of `self` and `other`, computed
+    /// with `squad_control_point`. Interpolating each consecutive pair of a
+    /// sequence of keyframes this way gives a curve with a continuous
+    /// angular velocity. As with `slerp`, all of the quaternions should be
+    /// normalized, and consecutive keyframes should have a positive dot
+    /// product: this function does not flip them to take the shortest path,
+    /// because that would break the continuity of the curve.
+    ///
+    /// See Shoemake, _Animating Rotation with Quaternion Curves_ (1985).
+    pub fn squad(
+        self,
+        a: Quaternion<S>,
+        b: Quaternion<S>,
+        other: Quaternion<S>,
+        amount: S,
+    ) -> Quaternion<S> {
+        let two: S = cast(2).unwrap();
+        let outer = self.slerp_unflipped(other, amount);
+        let inner = a.slerp_unflipped(b, amount);
+        outer.slerp_unflipped(inner, two * amount * (S::one() - amount))
+    }
+
+    /// Spherical linear interpolation between two unit quaternions, along
+    /// the arc from `self` to `other` even if it is not the shortest one.
+    fn slerp_unflipped(self, other: Quaternion<S>, amount: S) -> Quaternion<S> {
+        (self * (self.conjugate() * other).powf(amount)).normalize()
+    }
+
+    /// Split a unit quaternion into a swing and a twist, such that
+    /// `self == swing * twist`.
+    ///
+    /// The twist is the part of the rotation around `axis`, which should be
+    /// normalized, and the swing is the remaining rotation around an axis
+    /// perpendicular to it. Since the twist is applied first, `axis` is
+    /// expressed in the local frame of the rotated body, as is usual for
+    /// joint constraints. The twist is chosen to rotate by at most half a
+    /// turn. When `self` is a half turn around an axis perpendicular to
+    /// `axis`, the twist is undefined and the identity is returned.
+    pub fn swing_twist(self, axis: Vector3<S>) -> (Quaternion<S>, Quaternion<S>) {
+        let projected = axis * self.v.dot(axis);
+        let twist = Quaternion::from_sv(self.s, projected);
+        let magnitude2 = twist.magnitude2();
+
+        let twist = if ulps_eq!(magnitude2, &S::zero()) {
+            Quaternion::one()
+        } else if twist.s < S::zero() {
+            -twist / magnitude2.sqrt()
+        } else {
+            twist / magnitude2.sqrt()
+        };
+
+        (self * twist.conjugate(), twist)
+    }
+
+    /// The angle of the twist of a unit quaternion around `axis`, in the
+    /// range `[-pi, pi]`. See `swing_twist`.
+    pub fn twist_angle(self, axis: Vector3<S>) -> Rad<S> {
+        let (_, twist) = self.swing_twist(axis);
+        Rad::atan2(twist.v.dot(axis), twist.s) * cast(2).unwrap()
+    }
+
+    /// Limit the swing of a unit quaternion to a cone of half-angle
+    /// `max_angle` around `axis`, keeping its twist.
+    ///
+    /// If the swing rotates further than `max_angle`, it is replaced by a
+    /// rotation of `max_angle` around the same axis. `axis` should be
+    /// normalized.
+    pub fn clamp_swing<A: Into<Rad<S>>>(self, axis: Vector3<S>, max_angle: A) -> Quaternion<S> {
+        let (mut swing, twist) = self.swing_twist(axis);
+        if swing.s < S::zero() {
+            swing = -swing;
+        }
+
+        let max_angle = max_angle.into();
+        let angle = Rad::acos(swing.s.min(S::one())) * cast(2).unwrap();
+        if angle <= max_angle {
+            return self;
+        }
+
+        Quaternion::from_axis_angle(swing.v.normalize(), max_angle) * twist
+    }
+
+    /// Limit the twist of a unit quaternion around `axis` to the range
+    /// `[min, max]`, keeping its swing.
+    ///
+    /// The twist angle is measured in `[-pi, pi]`, as in `twist_angle`.
+    /// `axis` should be normalized.
+    pub fn clamp_twist<A: Into<Rad<S>>>(self, axis: Vector3<S>, min: A, max: A) -> Quaternion<S> {
+        let angle = self.twist_angle(axis);
+        let (min, max) = (min.into(), max.into());
+
+        let clamped = if angle < min {
+            min
+        } else if angle > max {
+            max
+        } else {
+            return self;
+        };
+
+        let (swing, _) = self.swing_twist(axis);
+        swing * Quaternion::from_axis_angle(axis, clamped)
+    }
+
+    /// The weighted average of a set of unit quaternions, using the
+    /// eigenvector method described by Markley et al. in _Averaging
+    /// Quaternions_ (2007).
+    ///
+    /// Each item is a non-negative weight and a unit quaternion. The result
+    /// minimizes the weighted sum of squared distances between the rotation
+    /// matrices, and does not depend on the sign of the inputs. It is
+    /// returned in the same hemisphere as the first quaternion. Returns
+    /// `None` if there are no items or the weights sum to zero.
+    ///
+    /// For quaternions that are close together, `nlerp_average` gives
+    /// almost the same result at a lower cost.
+    pub fn average<I>(items: I) -> Option<Quaternion<S>>
+    where
+        I: IntoIterator<Item = (S, Quaternion<S>)>,
+    {
+        let mut items = items.into_iter();
+        let (weight, first) = items.next()?;
+
+        let outer = |weight: S, q: Quaternion<S>| {
+            let v = Vector4::new(q.v.x, q.v.y, q.v.z, q.s) * weight;
+            Matrix4::from_cols(v * q.v.x, v * q.v.y, v * q.v.z, v * q.s)
+        };
+        let (total, m) = items.fold((weight, outer(weight, first)), |(total, m), (weight, q)| {
+            (total + weight, m + outer(weight, q))
+        });
+
+        if total <= S::zero() {
+            return None;
+        }
+
+        let v = max_eigenvector(m / total);
+        let q = Quaternion::new(v.w, v.x, v.y, v.z).normalize();
+        Some(if q.dot(first) < S::zero() { -q } else { q })
+    }
+
+    /// The weighted average of a set of unit quaternions, computed by
+    /// normalizing their weighted sum.
+    ///
+    /// Each item is a non-negative weight and a unit quaternion. Quaternions
+    /// that are in the opposite hemisphere to the first one are negated
+    /// before they are summed, as in `nlerp`. This is a good approximation
+    /// of `average` when the quaternions are close together. Returns `None`
+    /// if there are no items or the weighted sum is zero.
+    pub fn nlerp_average<I>(items: I) -> Option<Quaternion<S>>
+    where
+        I: IntoIterator<Item = (S, Quaternion<S>)>,
+    {
+        let mut items = items.into_iter();
+        let (weight, first) = items.next()?;
+
+        let sum = items.fold(first * weight, |sum, (weight, q)| {
+            if first.dot(q) < S::zero() {
+                sum - q * weight
+            } else {
+                sum + q * weight
+            }
+        });
+
+        if ulps_eq!(sum.magnitude2(), &S::zero()) {
+            None
+        } else {
+            Some(sum.normalize())
+        }
+    }
+
     pub fn is_finite(&self) -> bool {
         self.s.is_finite() && self.v.is_finite()
     }
+
+    /// Renormalize a rotation quaternion only if it has drifted too far from
+    /// unit length, that is if its squared magnitude differs from one by
+    /// more than `tolerance`. Calling this after every update of a long
+    /// running simulation keeps the quaternion a rotation while only rarely
+    /// paying for the square root.
+    ///
+    /// Small drifts are corrected with one Newton step, `q * (3 - |q|^2) / 2`,
+    /// and larger ones with a full normalization.
+    pub fn renormalize(self, tolerance: S) -> Quaternion<S> {
+        let half: S = cast(0.5f64).unwrap();
+        let error = S::one() - self.magnitude2();
+        if error.abs() <= tolerance {
+            self
+        } else if error.abs() <= cast(1e-2f64).unwrap() {
+            self * (S::one() + error * half)
+        } else {
+            self.normalize()
+        }
+    }
+}
+
+/// The eigenvector of the largest eigenvalue of a symmetric matrix.
+fn max_eigenvector<S: BaseFloat>(a: Matrix4<S>) -> Vector4<S> {
+    let (values, vectors) = jacobi_eigen(a);
+    let mut max = 0;
+    for i in 1..4 {
+        if values[i] > values[max] {
+            max = i;
+        }
+    }
+    vectors[max]
 }
 
 impl<S: BaseFloat> Zero for Quaternion<S> {
@@ -267,6 +555,32 @@ where
     }
 }
 
+impl<A> From<EulerAngles<A>> for Quaternion<A::Unitless>
+where
+    A: Angle + Into<Rad<<A as Angle>::Unitless>>,
+{
+    fn from(src: EulerAngles<A>) -> Quaternion<A::Unitless> {
+        let half = cast(0.5f64).unwrap();
+        let about = |axis: usize, angle: A| {
+            let (s, c) = Rad::sin_cos(angle.into() * half);
+            let mut v = Vector3::zero();
+            v[axis] = s;
+            Quaternion::from_sv(c, v)
+        };
+
+        let [i, j, k] = src.order.axes();
+        let (a, b, c) = (
+            about(i, src.first),
+            about(j, src.second),
+            about(k, src.third),
+        );
+        match src.frame {
+            EulerFrame::Intrinsic => a * b * c,
+            EulerFrame::Extrinsic => c * b * a,
+        }
+    }
+}
+
 impl_operator!(<S: BaseFloat> Neg for Quaternion<S> {
     fn neg(quat) -> Quaternion<S> {
         Quaternion::from_sv(-quat.s, -quat.v)
@@ -21,11 +21,12 @@ use structure::*;
 
 use angle::Rad;
 use approx;
-use euler::Euler;
+use euler::{Euler, EulerAngles};
 use matrix::{Matrix2, Matrix3};
 use num::{BaseFloat, BaseNum};
 use point::{Point2, Point3};
 use quaternion::Quaternion;
+use unit_complex::UnitComplex;
 use vector::{Vector2, Vector3};
 
 /// A trait for a generic rotation. A rotation is a transformation that
@@ -190,6 +191,13 @@ impl<S: BaseFloat> From<Basis2<S>> for Matrix2<S> {
     }
 }
 
+impl<S: BaseFloat> From<UnitComplex<S>> for Basis2<S> {
+    #[inline]
+    fn from(c: UnitComplex<S>) -> Basis2<S> {
+        Basis2 { mat: c.into() }
+    }
+}
+
 impl<S: BaseFloat> iter::Product<Basis2<S>> for Basis2<S> {
     #[inline]
     fn product<I: Iterator<Item = Basis2<S>>>(iter: I) -> Basis2<S> {
@@ -322,6 +330,24 @@ impl<S: BaseFloat> Basis3<S> {
             mat: (*quaternion).into(),
         }
     }
+
+    /// Create a rotation matrix from a matrix that is close to a rotation,
+    /// for example one that has drifted after many multiplications, using
+    /// `Matrix3::orthonormalize_symmetric`.
+    ///
+    /// Returns `None` if the matrix is singular or contains a reflection.
+    pub fn from_matrix_orthonormalized(mat: Matrix3<S>) -> Option<Basis3<S>> {
+        if mat.determinant() <= S::zero() {
+            return None;
+        }
+        mat.orthonormalize_symmetric().map(|mat| Basis3 { mat })
+    }
+
+    /// Wrap a matrix that is already known to be a rotation.
+    #[inline]
+    pub(crate) fn from_matrix_unchecked(mat: Matrix3<S>) -> Basis3<S> {
+        Basis3 { mat }
+    }
 }
 
 impl<S> AsRef<Matrix3<S>> for Basis3<S> {
@@ -481,6 +507,19 @@ where
     }
 }
 
+impl<A: Angle> From<EulerAngles<A>> for Basis3<A::Unitless>
+where
+    A: Into<Rad<<A as Angle>::Unitless>>,
+{
+    /// Create a three-dimensional rotation matrix from a set of euler angles
+    /// with a configurable rotation order.
+    fn from(src: EulerAngles<A>) -> Basis3<A::Unitless> {
+        Basis3 {
+            mat: Matrix3::from(src),
+        }
+    }
+}
+
 impl<S: fmt::Debug> fmt::Debug for Basis3<S> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "Basis3 ")?;
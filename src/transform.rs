@@ -18,7 +18,9 @@ use structure::*;
 use approx;
 use matrix::{Matrix2, Matrix3, Matrix4};
 use num::{BaseFloat, BaseNum};
+use num_traits::{cast, Float};
 use point::{Point2, Point3};
+use quaternion::Quaternion;
 use rotation::*;
 use vector::{Vector2, Vector3};
 
@@ -216,6 +218,80 @@ impl<S: BaseFloat, R: Rotation3<Scalar = S>> From<Decomposed<Vector3<S>, R>> for
     }
 }
 
+impl<S: BaseFloat> Decomposed<Vector3<S>, Quaternion<S>> {
+    /// Find the rotation and displacement that best map the `source` points
+    /// onto the corresponding `target` points in the least-squares sense,
+    /// using the [Kabsch algorithm](https://en.wikipedia.org/wiki/Kabsch_algorithm).
+    /// The result never contains a reflection.
+    ///
+    /// Returns the transformation together with the root mean square
+    /// distance between the transformed source points and the target points,
+    /// or `None` if the point sets are empty or differ in length.
+    pub fn fit_rigid(source: &[Point3<S>], target: &[Point3<S>]) -> Option<(Self, S)> {
+        Self::fit(source, target, false)
+    }
+
+    /// Find the uniform scale, rotation and displacement that best map the
+    /// `source` points onto the corresponding `target` points in the
+    /// least-squares sense, using Umeyama's method. The result never
+    /// contains a reflection.
+    ///
+    /// Returns the transformation together with the root mean square
+    /// distance between the transformed source points and the target points,
+    /// or `None` if the point sets are empty, differ in length, or all of
+    /// the source points coincide.
+    pub fn fit_similarity(source: &[Point3<S>], target: &[Point3<S>]) -> Option<(Self, S)> {
+        Self::fit(source, target, true)
+    }
+
+    fn fit(source: &[Point3<S>], target: &[Point3<S>], with_scale: bool) -> Option<(Self, S)> {
+        if source.is_empty() || source.len() != target.len() {
+            return None;
+        }
+        let n: S = cast(source.len()).unwrap();
+        let source_mean = Point3::centroid(source);
+        let target_mean = Point3::centroid(target);
+
+        // The cross-covariance of the centred point sets, and the variance of
+        // the source points.
+        let mut covariance = Matrix3::zero();
+        let mut variance = S::zero();
+        let mut extent = S::zero();
+        for (s, t) in source.iter().zip(target) {
+            extent += s.to_vec().magnitude2();
+            let s = s - source_mean;
+            let t = t - target_mean;
+            covariance += Matrix3::from_cols(t * s.x, t * s.y, t * s.z);
+            variance += s.magnitude2();
+        }
+
+        // `Matrix3::svd` keeps both bases proper rotations and moves any
+        // reflection into the sign of the last singular value, so `U * Vᵀ` is
+        // already the closest rotation and the signed singular values sum to
+        // the optimal scale times the source variance.
+        let svd = covariance.svd()?;
+        let rot = Quaternion::from(svd.u * svd.v.invert()).normalize();
+        let scale = if with_scale {
+            // The source points coincide if their spread is lost in the
+            // rounding error of their coordinates.
+            let ulps: S = cast(4).unwrap();
+            if variance.sqrt() <= ulps * S::epsilon() * extent.sqrt() {
+                return None;
+            }
+            svd.singular_values.sum() / variance
+        } else {
+            S::one()
+        };
+        let disp = target_mean - rot.rotate_point(source_mean * scale);
+
+        let transform = Decomposed { scale, rot, disp };
+        let error = source.iter().zip(target).fold(S::zero(), |sum, (&s, &t)| {
+            sum + transform.transform_point(s).distance2(t)
+        });
+        Some((transform, (error / n).sqrt()))
+    }
+}
+
 impl<S: BaseFloat, R: Rotation2<Scalar = S>> Transform2 for Decomposed<Vector2<S>, R> {
     type Scalar = S;
 }
@@ -283,10 +359,406 @@ where
     }
 }
 
+/// A transformation consisting of a per-axis scale, followed by a rotation
+/// and then a displacement, as used by the nodes of glTF and most modelling
+/// tools.
+///
+/// Unlike `Decomposed`, the set of these transforms is not closed under
+/// composition: rotating a non-uniformly scaled object and then scaling it
+/// again along different axes produces a shear, which cannot be represented
+/// by a scale and a rotation alone. See `Transform::concat` below for how
+/// this is handled.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Trs<V: VectorSpace, R> {
+    pub scale: V,
+    pub rot: R,
+    pub disp: V,
+}
+
+#[inline]
+fn has_singular_element<V>(v: &V) -> bool
+where
+    V: Array,
+    V::Element: BaseFloat,
+{
+    (0..V::len()).any(|i| v[i] == V::Element::zero() || !v[i].is_finite())
+}
+
+#[inline]
+fn is_uniform<V>(v: &V) -> bool
+where
+    V: Array,
+    V::Element: BaseFloat,
+{
+    (1..V::len()).all(|i| ulps_eq!(v[i], &v[0], epsilon = V::Element::zero()))
+}
+
+impl<P: EuclideanSpace, R: Rotation<Space = P>> One for Trs<P::Diff, R>
+where
+    P::Scalar: BaseFloat,
+    P::Diff: ElementWise + Array<Element = P::Scalar>,
+{
+    fn one() -> Self {
+        Trs {
+            scale: P::Diff::from_value(P::Scalar::one()),
+            rot: R::one(),
+            disp: P::Diff::zero(),
+        }
+    }
+}
+
+impl<P: EuclideanSpace, R: Rotation<Space = P>> Mul for Trs<P::Diff, R>
+where
+    P::Scalar: BaseFloat,
+    P::Diff: ElementWise + Array<Element = P::Scalar>,
+{
+    type Output = Self;
+
+    /// Multiplies the two transforms together, as described by
+    /// `Transform::concat`.
+    fn mul(self, rhs: Trs<P::Diff, R>) -> Self::Output {
+        self.concat(&rhs)
+    }
+}
+
+impl<P: EuclideanSpace, R: Rotation<Space = P>> Transform<P> for Trs<P::Diff, R>
+where
+    P::Scalar: BaseFloat,
+    P::Diff: ElementWise + Array<Element = P::Scalar>,
+{
+    #[inline]
+    fn look_at(eye: P, center: P, up: P::Diff) -> Trs<P::Diff, R> {
+        let rot = R::look_at(center - eye, up);
+        let disp = rot.rotate_vector(P::origin() - eye);
+        Trs {
+            scale: P::Diff::from_value(P::Scalar::one()),
+            rot,
+            disp,
+        }
+    }
+
+    #[inline]
+    fn look_at_rh(eye: P, center: P, up: P::Diff) -> Trs<P::Diff, R> {
+        let rot = R::look_at(eye - center, up);
+        let disp = rot.rotate_vector(P::origin() - eye);
+        Trs {
+            scale: P::Diff::from_value(P::Scalar::one()),
+            rot,
+            disp,
+        }
+    }
+
+    #[inline]
+    fn look_at_lh(eye: P, center: P, up: P::Diff) -> Trs<P::Diff, R> {
+        let rot = R::look_at(center - eye, up);
+        let disp = rot.rotate_vector(P::origin() - eye);
+        Trs {
+            scale: P::Diff::from_value(P::Scalar::one()),
+            rot,
+            disp,
+        }
+    }
+
+    #[inline]
+    fn transform_vector(&self, vec: P::Diff) -> P::Diff {
+        self.rot.rotate_vector(vec.mul_element_wise(self.scale))
+    }
+
+    /// Inverse transform a vector using this transform. This is exact even
+    /// when `inverse_transform` would fail because of shear.
+    #[inline]
+    fn inverse_transform_vector(&self, vec: P::Diff) -> Option<P::Diff> {
+        if has_singular_element(&self.scale) {
+            None
+        } else {
+            Some(
+                self.rot
+                    .invert()
+                    .rotate_vector(vec)
+                    .div_element_wise(self.scale),
+            )
+        }
+    }
+
+    #[inline]
+    fn transform_point(&self, point: P) -> P {
+        let scaled = P::from_vec(point.to_vec().mul_element_wise(self.scale));
+        self.rot.rotate_point(scaled) + self.disp
+    }
+
+    /// Combine this transform with another, such that `other` is applied
+    /// first.
+    ///
+    /// The rotations are multiplied and the scales are multiplied
+    /// component-wise, and the displacement is always exact. The result is
+    /// only exactly equal to the product of the two matrices when the scale
+    /// of `self` is uniform or the rotation of `other` is the identity;
+    /// otherwise the product contains a shear which is discarded. Convert to
+    /// a matrix first if the shear needs to be kept.
+    fn concat(&self, other: &Trs<P::Diff, R>) -> Trs<P::Diff, R> {
+        Trs {
+            scale: self.scale.mul_element_wise(other.scale),
+            rot: self.rot * other.rot,
+            disp: self
+                .rot
+                .rotate_vector(other.disp.mul_element_wise(self.scale))
+                + self.disp,
+        }
+    }
+
+    /// Create a transform that "un-does" this one.
+    ///
+    /// Returns `None` if any component of the scale is zero or not finite.
+    /// The inverse of a non-uniform scale followed by a rotation is a
+    /// rotation followed by a scale, which is a shear in general, so this
+    /// also returns `None` when the scale is non-uniform and the rotation is
+    /// not the identity. Use `inverse_transform_vector`, or convert to a
+    /// matrix, in that case.
+    fn inverse_transform(&self) -> Option<Trs<P::Diff, R>> {
+        if has_singular_element(&self.scale)
+            || !(is_uniform(&self.scale) || ulps_eq!(self.rot, &R::one()))
+        {
+            None
+        } else {
+            let s = P::Diff::from_value(P::Scalar::one()).div_element_wise(self.scale);
+            let r = self.rot.invert();
+            let d = r.rotate_vector(self.disp).mul_element_wise(s) * -P::Scalar::one();
+            Some(Trs {
+                scale: s,
+                rot: r,
+                disp: d,
+            })
+        }
+    }
+}
+
+impl<V: VectorSpace + Array<Element = <V as VectorSpace>::Scalar>, R> From<Decomposed<V, R>>
+    for Trs<V, R>
+{
+    #[inline]
+    fn from(dec: Decomposed<V, R>) -> Trs<V, R> {
+        Trs {
+            scale: V::from_value(dec.scale),
+            rot: dec.rot,
+            disp: dec.disp,
+        }
+    }
+}
+
+impl<S: BaseFloat, R: Rotation2<Scalar = S>> From<Trs<Vector2<S>, R>> for Matrix3<S> {
+    fn from(trs: Trs<Vector2<S>, R>) -> Matrix3<S> {
+        let m: Matrix2<_> = trs.rot.into();
+        let m = m * Matrix2::from_diagonal(trs.scale);
+        let mut m: Matrix3<_> = m.into();
+        m.z = trs.disp.extend(S::one());
+        m
+    }
+}
+
+impl<S: BaseFloat, R: Rotation3<Scalar = S>> From<Trs<Vector3<S>, R>> for Matrix4<S> {
+    fn from(trs: Trs<Vector3<S>, R>) -> Matrix4<S> {
+        let m: Matrix3<_> = trs.rot.into();
+        let m = m * Matrix3::from_diagonal(trs.scale);
+        let mut m: Matrix4<_> = m.into();
+        m.w = trs.disp.extend(S::one());
+        m
+    }
+}
+
+impl<S: BaseFloat> Trs<Vector3<S>, Quaternion<S>> {
+    /// Extract the scale, rotation and displacement of an affine matrix,
+    /// using `Matrix4::decompose`. Any shear in the matrix is discarded.
+    ///
+    /// Without shear, the scale along each axis is the length of the
+    /// corresponding column of the upper-left 3x3 block. With shear, the
+    /// columns are orthogonalized first, so the scale along the Y and Z axes
+    /// only counts the part of each column that is orthogonal to the
+    /// preceding ones. A reflection is folded into the scale along the X axis.
+    ///
+    /// Returns `None` if the bottom row of the matrix is not `[0, 0, 0, 1]`,
+    /// or if the matrix is singular.
+    pub fn from_matrix4(m: Matrix4<S>) -> Option<Trs<Vector3<S>, Quaternion<S>>> {
+        m.decompose().map(|dec| dec.to_trs())
+    }
+}
+
+impl<S: BaseFloat, R: Rotation2<Scalar = S>> Transform2 for Trs<Vector2<S>, R> {
+    type Scalar = S;
+}
+
+impl<S: BaseFloat, R: Rotation3<Scalar = S>> Transform3 for Trs<Vector3<S>, R> {
+    type Scalar = S;
+}
+
+impl<S: VectorSpace, R, E: BaseFloat> approx::AbsDiffEq for Trs<S, R>
+where
+    S: approx::AbsDiffEq<Epsilon = E>,
+    R: approx::AbsDiffEq<Epsilon = E>,
+{
+    type Epsilon = E;
+
+    #[inline]
+    fn default_epsilon() -> E {
+        E::default_epsilon()
+    }
+
+    #[inline]
+    fn abs_diff_eq(&self, other: &Self, epsilon: E) -> bool {
+        S::abs_diff_eq(&self.scale, &other.scale, epsilon)
+            && R::abs_diff_eq(&self.rot, &other.rot, epsilon)
+            && S::abs_diff_eq(&self.disp, &other.disp, epsilon)
+    }
+}
+
+impl<S: VectorSpace, R, E: BaseFloat> approx::RelativeEq for Trs<S, R>
+where
+    S: approx::RelativeEq<Epsilon = E>,
+    R: approx::RelativeEq<Epsilon = E>,
+{
+    #[inline]
+    fn default_max_relative() -> E {
+        E::default_max_relative()
+    }
+
+    #[inline]
+    fn relative_eq(&self, other: &Self, epsilon: E, max_relative: E) -> bool {
+        S::relative_eq(&self.scale, &other.scale, epsilon, max_relative)
+            && R::relative_eq(&self.rot, &other.rot, epsilon, max_relative)
+            && S::relative_eq(&self.disp, &other.disp, epsilon, max_relative)
+    }
+}
+
+impl<S: VectorSpace, R, E: BaseFloat> approx::UlpsEq for Trs<S, R>
+where
+    S: approx::UlpsEq<Epsilon = E>,
+    R: approx::UlpsEq<Epsilon = E>,
+{
+    #[inline]
+    fn default_max_ulps() -> u32 {
+        E::default_max_ulps()
+    }
+
+    #[inline]
+    fn ulps_eq(&self, other: &Self, epsilon: E, max_ulps: u32) -> bool {
+        S::ulps_eq(&self.scale, &other.scale, epsilon, max_ulps)
+            && R::ulps_eq(&self.rot, &other.rot, epsilon, max_ulps)
+            && S::ulps_eq(&self.disp, &other.disp, epsilon, max_ulps)
+    }
+}
+
+/// An affine transformation of 3-dimensional space, broken down into a
+/// scale, followed by a shear, a rotation and a displacement. This is the
+/// result of `Matrix4::decompose` and `Matrix3::decompose`.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct AffineDecomposition<S> {
+    /// The displacement, applied last.
+    pub disp: Vector3<S>,
+    /// The rotation.
+    pub rot: Quaternion<S>,
+    /// The shear factors `(xy, xz, yz)`. The shear maps the Y axis to
+    /// `y + xy * x` and the Z axis to `z + xz * x + yz * y`.
+    pub shear: Vector3<S>,
+    /// The scale along each axis, applied first. Negative if the
+    /// transformation contains a reflection.
+    pub scale: Vector3<S>,
+}
+
+impl<S: BaseFloat> AffineDecomposition<S> {
+    /// Returns `true` if the transformation mirrors space, which is the case
+    /// when the scale has an odd number of negative components.
+    #[inline]
+    pub fn is_reflection(&self) -> bool {
+        self.scale.product() < S::zero()
+    }
+
+    /// Returns `true` if the transformation contains a non-zero shear, and
+    /// so cannot be represented exactly by a `Trs`.
+    #[inline]
+    pub fn has_shear(&self) -> bool {
+        !ulps_eq!(self.shear, &Vector3::zero())
+    }
+
+    /// The shear as a matrix.
+    pub fn shear_matrix(&self) -> Matrix3<S> {
+        let mut m = Matrix3::identity();
+        m.y.x = self.shear.x;
+        m.z.x = self.shear.y;
+        m.z.y = self.shear.z;
+        m
+    }
+
+    /// Drop the shear, keeping the scale, rotation and displacement.
+    #[inline]
+    pub fn to_trs(&self) -> Trs<Vector3<S>, Quaternion<S>> {
+        Trs {
+            scale: self.scale,
+            rot: self.rot,
+            disp: self.disp,
+        }
+    }
+}
+
+impl<S: BaseFloat> From<AffineDecomposition<S>> for Matrix4<S> {
+    fn from(dec: AffineDecomposition<S>) -> Matrix4<S> {
+        let m = Matrix3::from(dec.rot) * dec.shear_matrix() * Matrix3::from_diagonal(dec.scale);
+        let mut m: Matrix4<_> = m.into();
+        m.w = dec.disp.extend(S::one());
+        m
+    }
+}
+
+impl<S: BaseFloat> approx::AbsDiffEq for AffineDecomposition<S> {
+    type Epsilon = S::Epsilon;
+
+    #[inline]
+    fn default_epsilon() -> S::Epsilon {
+        S::default_epsilon()
+    }
+
+    #[inline]
+    fn abs_diff_eq(&self, other: &Self, epsilon: S::Epsilon) -> bool {
+        Vector3::abs_diff_eq(&self.disp, &other.disp, epsilon)
+            && Quaternion::abs_diff_eq(&self.rot, &other.rot, epsilon)
+            && Vector3::abs_diff_eq(&self.shear, &other.shear, epsilon)
+            && Vector3::abs_diff_eq(&self.scale, &other.scale, epsilon)
+    }
+}
+
+impl<S: BaseFloat> approx::RelativeEq for AffineDecomposition<S> {
+    #[inline]
+    fn default_max_relative() -> S::Epsilon {
+        S::default_max_relative()
+    }
+
+    #[inline]
+    fn relative_eq(&self, other: &Self, epsilon: S::Epsilon, max_relative: S::Epsilon) -> bool {
+        Vector3::relative_eq(&self.disp, &other.disp, epsilon, max_relative)
+            && Quaternion::relative_eq(&self.rot, &other.rot, epsilon, max_relative)
+            && Vector3::relative_eq(&self.shear, &other.shear, epsilon, max_relative)
+            && Vector3::relative_eq(&self.scale, &other.scale, epsilon, max_relative)
+    }
+}
+
+impl<S: BaseFloat> approx::UlpsEq for AffineDecomposition<S> {
+    #[inline]
+    fn default_max_ulps() -> u32 {
+        S::default_max_ulps()
+    }
+
+    #[inline]
+    fn ulps_eq(&self, other: &Self, epsilon: S::Epsilon, max_ulps: u32) -> bool {
+        Vector3::ulps_eq(&self.disp, &other.disp, epsilon, max_ulps)
+            && Quaternion::ulps_eq(&self.rot, &other.rot, epsilon, max_ulps)
+            && Vector3::ulps_eq(&self.shear, &other.shear, epsilon, max_ulps)
+            && Vector3::ulps_eq(&self.scale, &other.scale, epsilon, max_ulps)
+    }
+}
+
 #[cfg(feature = "serde")]
 #[doc(hidden)]
 mod serde_ser {
-    use super::Decomposed;
+    use super::{Decomposed, Trs};
     use serde::ser::SerializeStruct;
     use serde::{self, Serialize};
     use structure::VectorSpace;
@@ -308,12 +780,29 @@ mod serde_ser {
             struc.end()
         }
     }
+
+    impl<V, R> Serialize for Trs<V, R>
+    where
+        V: Serialize + VectorSpace,
+        R: Serialize,
+    {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: serde::Serializer,
+        {
+            let mut struc = serializer.serialize_struct("Trs", 3)?;
+            struc.serialize_field("scale", &self.scale)?;
+            struc.serialize_field("rot", &self.rot)?;
+            struc.serialize_field("disp", &self.disp)?;
+            struc.end()
+        }
+    }
 }
 
 #[cfg(feature = "serde")]
 #[doc(hidden)]
 mod serde_de {
-    use super::Decomposed;
+    use super::{Decomposed, Trs};
     use serde::{self, Deserialize};
     use std::fmt;
     use std::marker::PhantomData;
@@ -425,4 +914,72 @@ mod serde_de {
             Ok(Decomposed { scale, rot, disp })
         }
     }
+
+    impl<'a, S: VectorSpace, R> Deserialize<'a> for Trs<S, R>
+    where
+        S: Deserialize<'a>,
+        R: Deserialize<'a>,
+    {
+        fn deserialize<D>(deserializer: D) -> Result<Trs<S, R>, D::Error>
+        where
+            D: serde::de::Deserializer<'a>,
+        {
+            const FIELDS: &[&str] = &["scale", "rot", "disp"];
+            deserializer.deserialize_struct("Trs", FIELDS, TrsVisitor(PhantomData))
+        }
+    }
+
+    struct TrsVisitor<S: VectorSpace, R>(PhantomData<(S, R)>);
+
+    impl<'a, S: VectorSpace, R> serde::de::Visitor<'a> for TrsVisitor<S, R>
+    where
+        S: Deserialize<'a>,
+        R: Deserialize<'a>,
+    {
+        type Value = Trs<S, R>;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            formatter.write_str("`scale`, `rot` and `disp` fields")
+        }
+
+        fn visit_map<V>(self, mut visitor: V) -> Result<Trs<S, R>, V::Error>
+        where
+            V: serde::de::MapAccess<'a>,
+        {
+            let mut scale = None;
+            let mut rot = None;
+            let mut disp = None;
+
+            while let Some(key) = visitor.next_key()? {
+                match key {
+                    DecomposedField::Scale => {
+                        scale = Some(visitor.next_value()?);
+                    }
+                    DecomposedField::Rot => {
+                        rot = Some(visitor.next_value()?);
+                    }
+                    DecomposedField::Disp => {
+                        disp = Some(visitor.next_value()?);
+                    }
+                }
+            }
+
+            let scale = match scale {
+                Some(scale) => scale,
+                None => return Err(serde::de::Error::missing_field("scale")),
+            };
+
+            let rot = match rot {
+                Some(rot) => rot,
+                None => return Err(serde::de::Error::missing_field("rot")),
+            };
+
+            let disp = match disp {
+                Some(disp) => disp,
+                None => return Err(serde::de::Error::missing_field("disp")),
+            };
+
+            Ok(Trs { scale, rot, disp })
+        }
+    }
 }
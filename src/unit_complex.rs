@@ -0,0 +1,234 @@
+// Copyright 2013-2014 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::iter;
+use std::ops::*;
+
+use structure::*;
+
+use angle::Rad;
+use approx;
+use matrix::{Matrix2, Matrix3};
+use num::BaseFloat;
+use point::Point2;
+use rotation::{Basis2, Rotation, Rotation2};
+use vector::Vector2;
+
+/// A unit complex number, `re + im * i`, representing a rotation in
+/// two-dimensional space.
+///
+/// The real part is the cosine of the angle of rotation, and the imaginary
+/// part its sine. Only two numbers are stored, so this is cheaper to store
+/// and renormalize than a [`Basis2`](struct.Basis2.html).
+///
+/// This type is marked as `#[repr(C)]`.
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct UnitComplex<S> {
+    /// The real part, the cosine of the angle.
+    pub re: S,
+    /// The imaginary part, the sine of the angle.
+    pub im: S,
+}
+
+impl<S> UnitComplex<S> {
+    /// Construct a new unit complex number from its real and imaginary
+    /// parts.
+    #[inline]
+    pub const fn new(re: S, im: S) -> UnitComplex<S> {
+        UnitComplex { re, im }
+    }
+}
+
+impl<S: BaseFloat> UnitComplex<S> {
+    /// The angle of the rotation, in the range `[-pi, pi]`.
+    #[inline]
+    pub fn angle(self) -> Rad<S> {
+        Rad::atan2(self.im, self.re)
+    }
+
+    /// The conjugate of the complex number, which for a unit complex number
+    /// is the inverse rotation.
+    #[inline]
+    pub fn conjugate(self) -> UnitComplex<S> {
+        UnitComplex::new(self.re, -self.im)
+    }
+
+    /// Rescale the complex number to unit length, to correct the drift
+    /// accumulated by repeated composition.
+    #[inline]
+    pub fn normalize(self) -> UnitComplex<S> {
+        let magnitude = (self.re * self.re + self.im * self.im).sqrt();
+        UnitComplex::new(self.re / magnitude, self.im / magnitude)
+    }
+
+    /// Interpolate between the rotation and `other` at a constant angular
+    /// velocity, by `amount`.
+    ///
+    /// This is the two-dimensional equivalent of `Quaternion::slerp`, and
+    /// takes the shortest path. When the rotations are exactly half a turn
+    /// apart, it turns counter-clockwise.
+    #[inline]
+    pub fn slerp(self, other: UnitComplex<S>, amount: S) -> UnitComplex<S> {
+        let delta = (self.conjugate() * other).angle();
+        self * UnitComplex::from_angle(delta * amount)
+    }
+
+    /// Are all entries in the complex number finite.
+    pub fn is_finite(&self) -> bool {
+        self.re.is_finite() && self.im.is_finite()
+    }
+}
+
+impl<S: BaseFloat> One for UnitComplex<S> {
+    #[inline]
+    fn one() -> UnitComplex<S> {
+        UnitComplex::new(S::one(), S::zero())
+    }
+}
+
+impl_operator!(<S: BaseFloat> Mul<UnitComplex<S> > for UnitComplex<S> {
+    fn mul(lhs, rhs) -> UnitComplex<S> {
+        UnitComplex::new(
+            lhs.re * rhs.re - lhs.im * rhs.im,
+            lhs.re * rhs.im + lhs.im * rhs.re,
+        )
+    }
+});
+
+impl_operator!(<S: BaseFloat> Mul<Vector2<S> > for UnitComplex<S> {
+    fn mul(lhs, rhs) -> Vector2<S> {
+        Vector2::new(
+            lhs.re * rhs.x - lhs.im * rhs.y,
+            lhs.im * rhs.x + lhs.re * rhs.y,
+        )
+    }
+});
+
+impl<S: BaseFloat> iter::Product<UnitComplex<S>> for UnitComplex<S> {
+    #[inline]
+    fn product<I: Iterator<Item = UnitComplex<S>>>(iter: I) -> UnitComplex<S> {
+        iter.fold(UnitComplex::one(), Mul::mul)
+    }
+}
+
+impl<'a, S: 'a + BaseFloat> iter::Product<&'a UnitComplex<S>> for UnitComplex<S> {
+    #[inline]
+    fn product<I: Iterator<Item = &'a UnitComplex<S>>>(iter: I) -> UnitComplex<S> {
+        iter.fold(UnitComplex::one(), Mul::mul)
+    }
+}
+
+impl<S: BaseFloat> Rotation for UnitComplex<S> {
+    type Space = Point2<S>;
+
+    /// Create the rotation that turns `unit_x()` to point at `dir`. A
+    /// rotation cannot flip `unit_y()`, so `up` is ignored.
+    #[inline]
+    fn look_at(dir: Vector2<S>, _up: Vector2<S>) -> UnitComplex<S> {
+        UnitComplex::new(dir.x, dir.y).normalize()
+    }
+
+    #[inline]
+    fn between_vectors(a: Vector2<S>, b: Vector2<S>) -> UnitComplex<S> {
+        UnitComplex::new(a.dot(b), a.perp_dot(b)).normalize()
+    }
+
+    #[inline]
+    fn rotate_vector(&self, vec: Vector2<S>) -> Vector2<S> {
+        self * vec
+    }
+
+    #[inline]
+    fn invert(&self) -> UnitComplex<S> {
+        self.conjugate()
+    }
+}
+
+impl<S: BaseFloat> Rotation2 for UnitComplex<S> {
+    type Scalar = S;
+
+    #[inline]
+    fn from_angle<A: Into<Rad<S>>>(theta: A) -> UnitComplex<S> {
+        let (s, c) = Rad::sin_cos(theta.into());
+        UnitComplex::new(c, s)
+    }
+}
+
+impl<S: BaseFloat> From<UnitComplex<S>> for Matrix2<S> {
+    #[inline]
+    fn from(src: UnitComplex<S>) -> Matrix2<S> {
+        Matrix2::new(src.re, src.im, -src.im, src.re)
+    }
+}
+
+impl<S: BaseFloat> From<UnitComplex<S>> for Matrix3<S> {
+    #[inline]
+    fn from(src: UnitComplex<S>) -> Matrix3<S> {
+        Matrix2::from(src).into()
+    }
+}
+
+impl<S: BaseFloat> From<Basis2<S>> for UnitComplex<S> {
+    #[inline]
+    fn from(src: Basis2<S>) -> UnitComplex<S> {
+        let m = src.as_ref();
+        UnitComplex::new(m.x.x, m.x.y)
+    }
+}
+
+impl<S: BaseFloat> approx::AbsDiffEq for UnitComplex<S> {
+    type Epsilon = S::Epsilon;
+
+    #[inline]
+    fn default_epsilon() -> S::Epsilon {
+        S::default_epsilon()
+    }
+
+    #[inline]
+    fn abs_diff_eq(&self, other: &Self, epsilon: S::Epsilon) -> bool {
+        S::abs_diff_eq(&self.re, &other.re, epsilon) && S::abs_diff_eq(&self.im, &other.im, epsilon)
+    }
+}
+
+impl<S: BaseFloat> approx::RelativeEq for UnitComplex<S> {
+    #[inline]
+    fn default_max_relative() -> S::Epsilon {
+        S::default_max_relative()
+    }
+
+    #[inline]
+    fn relative_eq(&self, other: &Self, epsilon: S::Epsilon, max_relative: S::Epsilon) -> bool {
+        S::relative_eq(&self.re, &other.re, epsilon, max_relative)
+            && S::relative_eq(&self.im, &other.im, epsilon, max_relative)
+    }
+}
+
+impl<S: BaseFloat> approx::UlpsEq for UnitComplex<S> {
+    #[inline]
+    fn default_max_ulps() -> u32 {
+        S::default_max_ulps()
+    }
+
+    #[inline]
+    fn ulps_eq(&self, other: &Self, epsilon: S::Epsilon, max_ulps: u32) -> bool {
+        S::ulps_eq(&self.re, &other.re, epsilon, max_ulps)
+            && S::ulps_eq(&self.im, &other.im, epsilon, max_ulps)
+    }
+}
+
+#[cfg(feature = "bytemuck")]
+impl_bytemuck_cast!(UnitComplex);
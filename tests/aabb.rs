@@ -0,0 +1,133 @@
+// Copyright 2013-2014 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+extern crate approx;
+extern crate cgmath;
+
+#[cfg(feature = "serde")]
+extern crate serde_json;
+
+use cgmath::*;
+
+#[test]
+fn test_new_orders_corners() {
+    let aabb = Aabb3::new(Point3::new(1.0, -2.0, 3.0), Point3::new(-1.0, 2.0, 0.0));
+    assert_eq!(aabb.min, Point3::new(-1.0, -2.0, 0.0));
+    assert_eq!(aabb.max, Point3::new(1.0, 2.0, 3.0));
+}
+
+#[test]
+fn test_from_points() {
+    let points = [
+        Point2::new(1, 1),
+        Point2::new(2, 3),
+        Point2::new(-3, 1),
+        Point2::new(0, -4),
+    ];
+    let aabb = Aabb2::from_points(points.iter().cloned()).unwrap();
+    assert_eq!(aabb, Aabb2::new(Point2::new(-3, -4), Point2::new(2, 3)));
+
+    assert_eq!(Aabb2::<f32>::from_points(Vec::new()), None);
+}
+
+#[test]
+fn test_union_intersection() {
+    let a = Aabb3::new(Point3::new(0.0, 0.0, 0.0), Point3::new(2.0, 2.0, 2.0));
+    let b = Aabb3::new(Point3::new(1.0, 1.0, 1.0), Point3::new(3.0, 3.0, 3.0));
+    let c = Aabb3::new(Point3::new(5.0, 0.0, 0.0), Point3::new(6.0, 1.0, 1.0));
+
+    assert_eq!(
+        a.union(b),
+        Aabb3::new(Point3::new(0.0, 0.0, 0.0), Point3::new(3.0, 3.0, 3.0))
+    );
+    assert_eq!(
+        a.intersection(b),
+        Some(Aabb3::new(
+            Point3::new(1.0, 1.0, 1.0),
+            Point3::new(2.0, 2.0, 2.0)
+        ))
+    );
+    assert!(a.intersects(b));
+    assert!(!a.intersects(c));
+    assert_eq!(a.intersection(c), None);
+}
+
+#[test]
+fn test_contains() {
+    let aabb = Aabb2::new(Point2::new(0.0, 0.0), Point2::new(2.0, 1.0));
+    assert!(aabb.contains(Point2::new(1.0, 0.5)));
+    assert!(aabb.contains(Point2::new(2.0, 1.0)));
+    assert!(!aabb.contains(Point2::new(2.5, 0.5)));
+
+    let inner = Aabb2::new(Point2::new(0.5, 0.25), Point2::new(1.5, 0.75));
+    assert!(aabb.contains_aabb(inner));
+    assert!(!inner.contains_aabb(aabb));
+}
+
+#[test]
+fn test_measures() {
+    let aabb = Aabb3::new(Point3::new(-1.0, 0.0, 1.0), Point3::new(1.0, 3.0, 5.0));
+    assert_eq!(aabb.extents(), Vector3::new(2.0, 3.0, 4.0));
+    assert_eq!(aabb.half_extents(), Vector3::new(1.0, 1.5, 2.0));
+    assert_eq!(aabb.center(), Point3::new(0.0, 1.5, 3.0));
+    assert_eq!(aabb.surface_area(), 52.0);
+    assert_eq!(aabb.volume(), 24.0);
+
+    let aabb = Aabb2::new(Point2::new(0, 0), Point2::new(3, 2));
+    assert_eq!(aabb.area(), 6);
+    assert_eq!(aabb.perimeter(), 10);
+}
+
+#[test]
+fn test_transform_matches_corners() {
+    let aabb = Aabb3::new(Point3::new(-1.0, 0.0, 1.0), Point3::new(1.0, 3.0, 5.0));
+    let transform = Decomposed {
+        scale: 2.0f64,
+        rot: Quaternion::from_axis_angle(Vector3::new(1.0, 1.0, 0.0).normalize(), Deg(30.0)),
+        disp: Vector3::new(1.0, -2.0, 3.0),
+    };
+
+    let expected =
+        Aabb3::from_points(aabb.corners().iter().map(|&p| transform.transform_point(p))).unwrap();
+
+    assert_relative_eq!(aabb.transform(transform), expected, epsilon = 1e-12);
+
+    let matrix: Matrix4<f64> = transform.into();
+    assert_relative_eq!(aabb.transform(matrix), expected, epsilon = 1e-12);
+}
+
+#[test]
+fn test_transform_2d() {
+    let aabb = Aabb2::new(Point2::new(0.0f64, 0.0), Point2::new(2.0, 1.0));
+    let m = Matrix3::from_translation(Vector2::new(1.0, 1.0))
+        * Matrix3::from(Matrix2::from_angle(Deg(90.0)));
+
+    assert_relative_eq!(
+        aabb.transform(m),
+        Aabb2::new(Point2::new(0.0, 1.0), Point2::new(1.0, 3.0)),
+        epsilon = 1e-12
+    );
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_serialize() {
+    let aabb = Aabb3::new(Point3::new(-1.0f64, 0.0, 1.0), Point3::new(1.0, 3.0, 5.0));
+
+    let serialized = serde_json::to_string(&aabb).unwrap();
+    let deserialized: Aabb3<f64> = serde_json::from_str(&serialized).unwrap();
+
+    assert_eq!(aabb, deserialized);
+}
@@ -0,0 +1,155 @@
+// Copyright 2013-2014 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+extern crate approx;
+extern crate cgmath;
+
+use cgmath::*;
+
+#[test]
+fn test_layout() {
+    let m = Matrix4::from_translation(Vector3::new(10.0, 20.0, 30.0))
+        * Matrix4::from_nonuniform_scale(1.0, 2.0, 3.0);
+    let a = Affine3::from_matrix4(m).unwrap();
+
+    assert_eq!(a.x, Vector4::new(1.0, 0.0, 0.0, 10.0));
+    assert_eq!(a.y, Vector4::new(0.0, 2.0, 0.0, 20.0));
+    assert_eq!(a.z, Vector4::new(0.0, 0.0, 3.0, 30.0));
+    assert_eq!(Matrix4::from(a), m);
+
+    assert_eq!(
+        Affine3::from_matrix4(perspective(Deg(60.0), 1.0, 0.1, 10.0)),
+        None
+    );
+    assert_eq!(
+        Affine2::from_matrix3(Matrix3::new(1.0, 0.0, 1.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0)),
+        None
+    );
+}
+
+#[test]
+fn test_matches_matrix3() {
+    let d = Decomposed {
+        scale: 0.5,
+        rot: Basis2::from_angle(Deg(-70.0)),
+        disp: Vector2::new(1.0, 4.0),
+    };
+    let a = Affine2::from(d);
+    let m = Matrix3::from(d);
+    let p = Point2::new(2.0, -3.0);
+    let v = Vector2::new(-1.0, 5.0);
+
+    assert_relative_eq!(Matrix3::from(a), m, epsilon = 1e-12);
+    assert_eq!(Affine2::from_matrix3(Matrix3::from(a)), Some(a));
+    assert_relative_eq!(a.transform_point(p), m.transform_point(p), epsilon = 1e-12);
+    assert_relative_eq!(
+        a.transform_vector(v),
+        (m * v.extend(0.0)).truncate(),
+        epsilon = 1e-12
+    );
+
+    let b = Affine2::from_parts(Matrix2::new(1.0, 0.5, -2.0, 3.0), Vector2::new(0.0, 1.0));
+    assert_relative_eq!(
+        Matrix3::from(a * b),
+        Matrix3::from(a) * Matrix3::from(b),
+        epsilon = 1e-12
+    );
+    assert_relative_eq!(
+        Matrix3::from(b.invert().unwrap()),
+        Matrix3::from(b).invert().unwrap(),
+        epsilon = 1e-12
+    );
+    assert_relative_eq!(
+        a * a.inverse_transform().unwrap(),
+        Affine2::one(),
+        epsilon = 1e-12
+    );
+}
+
+#[test]
+fn test_matches_matrix4() {
+    let d = Decomposed {
+        scale: 2.0,
+        rot: Quaternion::from_axis_angle(Vector3::new(1.0, 2.0, 2.0).normalize(), Deg(40.0)),
+        disp: Vector3::new(3.0, -2.0, 1.0),
+    };
+    let a = Affine3::from(d);
+    let m = Matrix4::from(d);
+    let p = Point3::new(2.0, -3.0, 0.5);
+    let v = Vector3::new(-1.0, 5.0, 2.0);
+
+    assert_relative_eq!(Matrix4::from(a), m, epsilon = 1e-12);
+    assert_relative_eq!(a.transform_point(p), m.transform_point(p), epsilon = 1e-12);
+    assert_relative_eq!(
+        a.transform_vector(v),
+        (m * v.extend(0.0)).truncate(),
+        epsilon = 1e-12
+    );
+
+    let b = Affine3::from_parts(
+        Matrix3::new(1.0, 0.5, 0.0, -2.0, 3.0, 1.0, 0.0, 0.0, 2.0),
+        Vector3::new(0.0, 1.0, -1.0),
+    );
+    assert_relative_eq!(
+        Matrix4::from(a * b),
+        Matrix4::from(a) * Matrix4::from(b),
+        epsilon = 1e-12
+    );
+    assert_relative_eq!(
+        Matrix4::from(b.invert().unwrap()),
+        Matrix4::from(b).invert().unwrap(),
+        epsilon = 1e-12
+    );
+    assert_relative_eq!(
+        a * a.inverse_transform().unwrap(),
+        Affine3::one(),
+        epsilon = 1e-12
+    );
+
+    let eye = Point3::new(1.0, 2.0, 3.0);
+    let center = Point3::new(0.0, 0.0, -1.0);
+    assert_relative_eq!(
+        Matrix4::from(Affine3::look_at_rh(eye, center, Vector3::unit_y())),
+        Matrix4::look_at_rh(eye, center, Vector3::unit_y()),
+        epsilon = 1e-12
+    );
+}
+
+#[test]
+fn test_to_decomposed() {
+    let d = Decomposed {
+        scale: 2.0,
+        rot: Quaternion::from_axis_angle(Vector3::new(1.0, 2.0, 2.0).normalize(), Deg(40.0)),
+        disp: Vector3::new(3.0, -2.0, 1.0),
+    };
+    let dec = Affine3::from(d).to_decomposed().unwrap();
+    assert_relative_eq!(dec, d, epsilon = 1e-12);
+
+    let d = Decomposed {
+        scale: 0.5,
+        rot: Basis2::from_angle(Deg(-70.0)),
+        disp: Vector2::new(1.0, 4.0),
+    };
+    let dec = Affine2::from(d).to_decomposed().unwrap();
+    assert_relative_eq!(dec, d, epsilon = 1e-12);
+
+    let stretched = Affine3::from_parts(
+        Matrix3::from_diagonal(Vector3::new(1.0, 2.0, 1.0)),
+        Vector3::zero(),
+    );
+    assert!(stretched.to_decomposed().is_none());
+    let mirrored = Affine2::from_parts(Matrix2::new(-1.0, 0.0, 0.0, 1.0), Vector2::zero());
+    assert!(mirrored.to_decomposed().is_none());
+}
@@ -0,0 +1,130 @@
+// Copyright 2013-2014 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+extern crate approx;
+extern crate cgmath;
+
+use cgmath::*;
+use std::f64::consts::PI;
+
+const ANGLES: [f64; 7] = [1e-12, 1e-6, 0.3, 1.5, 3.0, PI - 1e-6, PI];
+
+#[test]
+fn test_quaternion_round_trip() {
+    let axis = Vector3::new(2.0, -1.0, 2.0) / 3.0;
+    for &angle in ANGLES.iter() {
+        let axis_angle = AxisAngle::new(axis, Rad(angle));
+        let q = Quaternion::from(axis_angle);
+        assert_relative_eq!(q, Quaternion::from_axis_angle(axis, Rad(angle)));
+        assert_relative_eq!(AxisAngle::from(q), axis_angle, max_relative = 1e-12);
+
+        let v = RotationVector::new(axis * angle);
+        assert_relative_eq!(Quaternion::from(v), q, epsilon = 1e-15);
+        assert_relative_eq!(RotationVector::from(q), v, max_relative = 1e-12);
+    }
+}
+
+#[test]
+fn test_matrix_round_trip() {
+    let axis = Vector3::new(2.0, -1.0, 2.0) / 3.0;
+    for &angle in ANGLES[1..].iter() {
+        let axis_angle = AxisAngle::new(axis, Rad(angle));
+        let m = Matrix3::from(axis_angle);
+        assert_relative_eq!(
+            m,
+            Matrix3::from_axis_angle(axis, Rad(angle)),
+            epsilon = 1e-15
+        );
+        assert_relative_eq!(AxisAngle::from(m), axis_angle, epsilon = 1e-9);
+
+        let basis = Basis3::from(axis_angle);
+        assert_relative_eq!(AxisAngle::from(basis), axis_angle, epsilon = 1e-9);
+
+        let v = RotationVector::from(axis_angle);
+        assert_relative_eq!(RotationVector::from(Matrix3::from(v)), v, epsilon = 1e-9);
+    }
+}
+
+#[test]
+fn test_identity() {
+    assert_eq!(
+        AxisAngle::from(Quaternion::<f64>::one()),
+        AxisAngle::new(Vector3::unit_x(), Rad(0.0))
+    );
+    assert_eq!(
+        AxisAngle::from(Matrix3::<f64>::identity()),
+        AxisAngle::one()
+    );
+    assert_eq!(
+        RotationVector::from(Quaternion::<f64>::one()),
+        RotationVector::one()
+    );
+    assert_eq!(
+        AxisAngle::from(RotationVector::<f64>::one()),
+        AxisAngle::one()
+    );
+}
+
+#[test]
+fn test_canonical_angle() {
+    let axis = Vector3::new(2.0, -1.0, 2.0) / 3.0;
+    // Negative angles and angles above half a turn come back as the
+    // equivalent rotation in [0, pi].
+    let q = Quaternion::from(AxisAngle::new(axis, Rad(-0.5)));
+    assert_relative_eq!(
+        AxisAngle::from(q),
+        AxisAngle::new(-axis, Rad(0.5)),
+        epsilon = 1e-12
+    );
+
+    let q = Quaternion::from(RotationVector::new(axis * 4.0));
+    assert_relative_eq!(
+        RotationVector::from(q),
+        RotationVector::new(axis * (4.0 - 2.0 * PI)),
+        epsilon = 1e-12
+    );
+}
+
+#[test]
+fn test_rotation() {
+    let axis = Vector3::new(2.0, -1.0, 2.0) / 3.0;
+    let a = AxisAngle::from_axis_angle(axis, Deg(75.0));
+    let b = AxisAngle::from_angle_z(Deg(-40.0));
+    let (qa, qb) = (Quaternion::from(a), Quaternion::from(b));
+    let v = Vector3::new(1.0, 2.0, 3.0);
+
+    assert_relative_eq!(a.rotate_vector(v), qa.rotate_vector(v), epsilon = 1e-12);
+    assert_relative_eq!(Quaternion::from(a * b), qa * qb, epsilon = 1e-12);
+    assert_relative_eq!((a * a.invert()).angle, Rad(0.0), epsilon = 1e-12);
+
+    let (ra, rb) = (RotationVector::from(a), RotationVector::from(b));
+    assert_relative_eq!(ra.rotate_vector(v), qa.rotate_vector(v), epsilon = 1e-12);
+    assert_relative_eq!(Quaternion::from(ra * rb), qa * qb, epsilon = 1e-12);
+    assert_relative_eq!(ra * ra.invert(), RotationVector::one(), epsilon = 1e-12);
+    assert_relative_eq!(ra.angle(), Rad::from(Deg(75.0)), epsilon = 1e-12);
+
+    let product: RotationVector<f64> = [ra, rb].iter().product();
+    assert_relative_eq!(product, ra * rb, epsilon = 1e-12);
+}
+
+#[test]
+fn test_from_euler() {
+    let euler = Euler::new(Rad(0.3), Rad(-0.2), Rad(1.2));
+    assert_relative_eq!(
+        Quaternion::from(AxisAngle::from(euler)),
+        Quaternion::from(euler),
+        epsilon = 1e-12
+    );
+}
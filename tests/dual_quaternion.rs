@@ -0,0 +1,204 @@
+// Copyright 2014 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+extern crate approx;
+extern crate cgmath;
+
+#[cfg(feature = "serde")]
+extern crate serde_json;
+
+use cgmath::*;
+
+#[test]
+fn test_from_rotation_translation() {
+    let t = Decomposed {
+        scale: 1.0,
+        rot: Quaternion::from_axis_angle(Vector3::new(1.0, 2.0, 3.0).normalize(), Deg(75.0)),
+        disp: Vector3::new(4.0, -5.0, 6.0),
+    };
+    let dq = DualQuaternion::from_rotation_translation(t.rot, t.disp);
+
+    assert_relative_eq!(dq.rotation(), t.rot, epsilon = 1e-12);
+    assert_relative_eq!(dq.translation(), t.disp, epsilon = 1e-12);
+    assert_relative_eq!(DualQuaternion::from(t), dq, epsilon = 1e-12);
+    assert_relative_eq!(Decomposed::from(dq), t, epsilon = 1e-12);
+    assert_relative_eq!(Matrix4::from(dq), Matrix4::from(t), epsilon = 1e-12);
+}
+
+#[test]
+fn test_transform() {
+    let t = Decomposed {
+        scale: 1.0,
+        rot: Quaternion::from_axis_angle(Vector3::new(1.0, 2.0, 3.0).normalize(), Deg(75.0)),
+        disp: Vector3::new(4.0, -5.0, 6.0),
+    };
+    let dq = DualQuaternion::from(t);
+    let p = Point3::new(1.0, -2.0, 0.5);
+    let v = Vector3::new(-3.0, 0.25, 1.0);
+
+    assert_relative_eq!(dq.transform_point(p), t.transform_point(p), epsilon = 1e-12);
+    assert_relative_eq!(
+        dq.transform_vector(v),
+        t.transform_vector(v),
+        epsilon = 1e-12
+    );
+}
+
+#[test]
+fn test_compose_invert() {
+    let a = Decomposed {
+        scale: 1.0,
+        rot: Quaternion::from_axis_angle(Vector3::new(1.0, 2.0, 3.0).normalize(), Deg(75.0)),
+        disp: Vector3::new(4.0, -5.0, 6.0),
+    };
+    let b = Decomposed {
+        scale: 1.0,
+        rot: Quaternion::from_angle_x(Deg(-40.0)),
+        disp: Vector3::new(0.5, 1.0, -2.0),
+    };
+    let (dqa, dqb) = (DualQuaternion::from(a), DualQuaternion::from(b));
+
+    assert_relative_eq!(
+        Matrix4::from(dqa * dqb),
+        Matrix4::from(a * b),
+        epsilon = 1e-12
+    );
+    assert_relative_eq!(dqa * dqa.invert(), DualQuaternion::one(), epsilon = 1e-12);
+    assert_relative_eq!(dqa.conjugate(), dqa.invert(), epsilon = 1e-12);
+    assert_relative_eq!(
+        dqa.inverse_transform().unwrap(),
+        DualQuaternion::from(a.inverse_transform().unwrap()),
+        epsilon = 1e-12
+    );
+}
+
+#[test]
+fn test_normalize() {
+    let dq = DualQuaternion::from_rotation_translation(
+        Quaternion::from_axis_angle(Vector3::new(1.0, 2.0, 3.0).normalize(), Deg(75.0)),
+        Vector3::new(4.0, -5.0, 6.0),
+    );
+    let scaled = DualQuaternion::new(dq.real * 3.0, dq.dual * 3.0 + dq.real * 0.5);
+
+    let n = scaled.normalize();
+    assert_relative_eq!(n, dq, epsilon = 1e-12);
+    assert_relative_eq!(n.real.magnitude(), 1.0, epsilon = 1e-12);
+    assert_relative_eq!(n.real.dot(n.dual), 0.0, epsilon = 1e-12);
+}
+
+#[test]
+fn test_sclerp() {
+    let a = DualQuaternion::from_translation(Vector3::new(1.0, 0.0, 0.0));
+    let b = DualQuaternion::from_rotation_translation(
+        Quaternion::from_angle_z(Deg(90.0)),
+        Vector3::new(1.0, 0.0, 2.0),
+    );
+
+    assert_relative_eq!(a.sclerp(b, 0.0), a, epsilon = 1e-12);
+    assert_relative_eq!(a.sclerp(b, 1.0), b, epsilon = 1e-12);
+
+    // Halfway along the screw: rotated 45 degrees about the Z axis through
+    // the point (1, 0, 0), and translated halfway along it.
+    let mid = a.sclerp(b, 0.5);
+    assert_relative_eq!(
+        mid.rotation(),
+        Quaternion::from_angle_z(Deg(45.0)),
+        epsilon = 1e-12
+    );
+    assert_relative_eq!(
+        mid.translation(),
+        Vector3::new(1.0, 0.0, 1.0),
+        epsilon = 1e-12
+    );
+
+    // Both representations of the target give the same path.
+    assert_relative_eq!(a.sclerp(-b, 0.5), mid, epsilon = 1e-12);
+
+    // Pure translations interpolate linearly.
+    let c = DualQuaternion::from_translation(Vector3::new(3.0, 4.0, 0.0));
+    assert_relative_eq!(
+        a.sclerp(c, 0.25).translation(),
+        Vector3::new(1.5, 1.0, 0.0),
+        epsilon = 1e-12
+    );
+}
+
+#[test]
+fn test_screw_pitch() {
+    // A screw about the Z axis, through the point (2, 0, 0).
+    let pivot = Vector3::new(2.0, 0.0, 0.0);
+    let rot = Quaternion::from_angle_z(Deg(120.0));
+    let dq = DualQuaternion::from_translation(pivot + Vector3::unit_z() * 3.0)
+        * DualQuaternion::from_rotation(rot)
+        * DualQuaternion::from_translation(-pivot);
+
+    let third = dq.powf(1.0 / 3.0);
+    assert_relative_eq!(third * third * third, dq, epsilon = 1e-12);
+    assert_relative_eq!(
+        third.transform_point(Point3::new(2.0, 0.0, 0.0)),
+        Point3::new(2.0, 0.0, 1.0),
+        epsilon = 1e-12
+    );
+}
+
+#[test]
+fn test_dlb() {
+    let a = DualQuaternion::from_rotation_translation(
+        Quaternion::from_axis_angle(Vector3::new(1.0, 2.0, 3.0).normalize(), Deg(75.0)),
+        Vector3::new(4.0, -5.0, 6.0),
+    );
+    let b = DualQuaternion::from_rotation_translation(
+        Quaternion::from_angle_y(Deg(20.0)),
+        Vector3::new(1.0, 1.0, 1.0),
+    );
+
+    assert_relative_eq!(
+        DualQuaternion::dlb(vec![(1.0, a)]).unwrap(),
+        a,
+        epsilon = 1e-12
+    );
+    assert_relative_eq!(
+        DualQuaternion::dlb(vec![(0.0, a), (1.0, b)]).unwrap(),
+        b,
+        epsilon = 1e-12
+    );
+
+    // The hemisphere of each input does not matter.
+    let blend = DualQuaternion::dlb(vec![(0.3, a), (0.7, b)]).unwrap();
+    assert_relative_eq!(
+        DualQuaternion::dlb(vec![(0.3, a), (0.7, -b)]).unwrap(),
+        blend,
+        epsilon = 1e-12
+    );
+    assert_relative_eq!(blend.real.magnitude(), 1.0, epsilon = 1e-12);
+    assert_relative_eq!(blend.real.dot(blend.dual), 0.0, epsilon = 1e-12);
+
+    assert_eq!(DualQuaternion::<f64>::dlb(vec![]), None);
+    assert_eq!(DualQuaternion::dlb(vec![(1.0, a), (-1.0, a)]), None);
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_serialize() {
+    let dq = DualQuaternion::from_rotation_translation(
+        Quaternion::from_axis_angle(Vector3::new(1.0, 2.0, 3.0).normalize(), Deg(75.0)),
+        Vector3::new(4.0, -5.0, 6.0),
+    );
+
+    let serialized = serde_json::to_string(&dq).unwrap();
+    let deserialized: DualQuaternion<f64> = serde_json::from_str(&serialized).unwrap();
+
+    assert_eq!(dq, deserialized);
+}
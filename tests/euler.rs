@@ -0,0 +1,243 @@
+// Copyright 2013-2014 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+extern crate approx;
+extern crate cgmath;
+
+use cgmath::*;
+
+const ORDERS: [EulerOrder; 12] = [
+    EulerOrder::Xyz,
+    EulerOrder::Xzy,
+    EulerOrder::Yxz,
+    EulerOrder::Yzx,
+    EulerOrder::Zxy,
+    EulerOrder::Zyx,
+    EulerOrder::Xyx,
+    EulerOrder::Xzx,
+    EulerOrder::Yxy,
+    EulerOrder::Yzy,
+    EulerOrder::Zxz,
+    EulerOrder::Zyz,
+];
+
+const FRAMES: [EulerFrame; 2] = [EulerFrame::Intrinsic, EulerFrame::Extrinsic];
+
+fn axis_matrix(axis: usize, angle: Rad<f64>) -> Matrix3<f64> {
+    match axis {
+        0 => Matrix3::from_angle_x(angle),
+        1 => Matrix3::from_angle_y(angle),
+        _ => Matrix3::from_angle_z(angle),
+    }
+}
+
+#[test]
+fn test_intrinsic_xyz_matches_euler() {
+    let euler = Euler::new(Rad(0.4f64), Rad(-0.9), Rad(2.1));
+    let angles = EulerAngles::from(euler);
+
+    assert_ulps_eq!(Quaternion::from(angles), Quaternion::from(euler));
+    assert_ulps_eq!(Matrix3::from(angles), Matrix3::from(euler));
+    assert_ulps_eq!(Matrix4::from(angles), Matrix4::from(euler));
+}
+
+#[test]
+fn test_composition_order() {
+    let (a, b, c) = (Rad(0.3f64), Rad(-1.2), Rad(0.8));
+    for &order in ORDERS.iter() {
+        let [i, j, k] = order.axes();
+        let (ra, rb, rc) = (axis_matrix(i, a), axis_matrix(j, b), axis_matrix(k, c));
+
+        let intrinsic = EulerAngles::new(a, b, c, order, EulerFrame::Intrinsic);
+        assert_relative_eq!(Matrix3::from(intrinsic), ra * rb * rc, epsilon = 1e-12);
+
+        let extrinsic = EulerAngles::new(a, b, c, order, EulerFrame::Extrinsic);
+        assert_relative_eq!(Matrix3::from(extrinsic), rc * rb * ra, epsilon = 1e-12);
+    }
+}
+
+#[test]
+fn test_quaternion_matches_matrix() {
+    for &order in ORDERS.iter() {
+        for &frame in FRAMES.iter() {
+            let angles = EulerAngles::new(Deg(25.0f64), Deg(70.0), Deg(-140.0), order, frame);
+            let from_quaternion = Matrix3::from(Quaternion::from(angles));
+
+            assert_relative_eq!(from_quaternion, Matrix3::from(angles), epsilon = 1e-12);
+            assert_relative_eq!(
+                Basis3::from(angles).as_ref(),
+                &Matrix3::from(angles),
+                epsilon = 1e-12
+            );
+        }
+    }
+}
+
+#[test]
+fn test_round_trip() {
+    for &order in ORDERS.iter() {
+        for &frame in FRAMES.iter() {
+            for &second in [0.7f64, 1.4, -0.2].iter() {
+                let second = if order.is_proper() {
+                    second.abs()
+                } else {
+                    second
+                };
+                let angles = EulerAngles::new(Rad(-2.5f64), Rad(second), Rad(1.1), order, frame);
+
+                let from_matrix = EulerAngles::from_matrix3(Matrix3::from(angles), order, frame);
+                assert_relative_eq!(from_matrix, angles, epsilon = 1e-12);
+
+                let from_quaternion =
+                    EulerAngles::from_quaternion(Quaternion::from(angles), order, frame);
+                assert_relative_eq!(from_quaternion, angles, epsilon = 1e-12);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_gimbal_lock() {
+    for &order in ORDERS.iter() {
+        let locked: &[f64] = if order.is_proper() {
+            &[0.0, std::f64::consts::PI]
+        } else {
+            &[std::f64::consts::FRAC_PI_2, -std::f64::consts::FRAC_PI_2]
+        };
+
+        for &frame in FRAMES.iter() {
+            for &second in locked.iter() {
+                let angles = EulerAngles::new(Rad(0.4f64), Rad(second), Rad(-0.9), order, frame);
+                let m = Matrix3::from(angles);
+                let solved = EulerAngles::from_matrix3(m, order, frame);
+
+                assert_eq!(solved.first, Rad(0.0));
+                assert_relative_eq!(solved.second, Rad(second), epsilon = 1e-12);
+                assert_relative_eq!(Matrix3::from(solved), m, epsilon = 1e-12);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_to_frame() {
+    let angles = EulerAngles::new(
+        Deg(30.0f64),
+        Deg(10.0),
+        Deg(-5.0),
+        EulerOrder::Zyx,
+        EulerFrame::Intrinsic,
+    );
+    let extrinsic = angles.to_frame(EulerFrame::Extrinsic);
+
+    assert_eq!(extrinsic.order, EulerOrder::Xyz);
+    assert_eq!(extrinsic.first, Deg(-5.0));
+    assert_eq!(extrinsic.third, Deg(30.0));
+    assert_relative_eq!(
+        Quaternion::from(extrinsic),
+        Quaternion::from(angles),
+        epsilon = 1e-12
+    );
+    assert_eq!(extrinsic.to_frame(EulerFrame::Intrinsic), angles);
+}
+
+#[test]
+fn test_euler_from_matrix() {
+    let euler = Euler::new(Rad(0.4f64), Rad(-0.9), Rad(2.1));
+    let m = Matrix3::from(euler);
+
+    assert_relative_eq!(Euler::from(m), euler, epsilon = 1e-12);
+    assert_relative_eq!(Euler::from(Basis3::from(euler)), euler, epsilon = 1e-12);
+    assert_relative_eq!(
+        Euler::from(m),
+        Euler::from(Quaternion::from(euler)),
+        epsilon = 1e-12
+    );
+}
+
+#[test]
+fn test_solutions() {
+    for &order in ORDERS.iter() {
+        for &frame in FRAMES.iter() {
+            let angles = EulerAngles::new(Rad(0.5f64), Rad(1.0), Rad(-2.0), order, frame);
+            let m = Matrix3::from(angles);
+            let [a, b] = EulerAngles::from_matrix3_solutions(m, order, frame);
+
+            assert_relative_eq!(a, angles, epsilon = 1e-12);
+            assert_relative_eq!(Matrix3::from(b), m, epsilon = 1e-12);
+            assert!((b.second - a.second).0.abs() > 0.1);
+        }
+    }
+}
+
+#[test]
+fn test_near_picks_alternate_solution() {
+    let euler = Euler::new(Rad(0.5f64), Rad(1.0), Rad(-2.0));
+    let m = Matrix3::from(euler);
+    let [_, alternate] = Euler::from_matrix3_solutions(m);
+
+    let reference = Euler::new(
+        alternate.x + Rad(0.05),
+        alternate.y,
+        alternate.z - Rad(0.05),
+    );
+    assert_relative_eq!(
+        Euler::from_matrix3_near(m, reference),
+        alternate,
+        epsilon = 1e-12
+    );
+    assert_relative_eq!(Euler::from_matrix3_near(m, euler), euler, epsilon = 1e-12);
+}
+
+#[test]
+fn test_near_unwraps_angles() {
+    let reference = EulerAngles::new(
+        Rad(3.1f64),
+        Rad(0.2),
+        Rad(-3.1),
+        EulerOrder::Zxz,
+        EulerFrame::Extrinsic,
+    );
+    let rotated = EulerAngles::new(
+        Rad(3.2f64),
+        Rad(0.25),
+        Rad(-3.2),
+        reference.order,
+        reference.frame,
+    );
+    let near = EulerAngles::from_matrix3_near(Matrix3::from(rotated), reference);
+
+    assert_relative_eq!(near, rotated, epsilon = 1e-12);
+}
+
+#[test]
+fn test_near_is_continuous() {
+    // Pitch past the pole, where the principal solution flips yaw and roll
+    // by half a turn.
+    let order = EulerOrder::Zyx;
+    let frame = EulerFrame::Intrinsic;
+    let mut previous = EulerAngles::new(Rad(0.3f64), Rad(1.2), Rad(-0.4), order, frame);
+
+    for step in 1..40 {
+        let pitch = Rad(1.2 + 0.02 * step as f64);
+        let m = Matrix3::from(EulerAngles::new(Rad(0.3), pitch, Rad(-0.4), order, frame));
+        let current = EulerAngles::from_matrix3_near(m, previous);
+
+        assert_relative_eq!(current.first, Rad(0.3), epsilon = 1e-9);
+        assert_relative_eq!(current.second, pitch, epsilon = 1e-9);
+        assert_relative_eq!(current.third, Rad(-0.4), epsilon = 1e-9);
+        previous = current;
+    }
+}
@@ -0,0 +1,270 @@
+// Copyright 2013-2014 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+extern crate approx;
+extern crate cgmath;
+
+use cgmath::*;
+
+#[test]
+fn test_identity_planes() {
+    let frustum = Frustum::from_matrix4(
+        Matrix4::identity(),
+        PerspectiveMode::Standard,
+        ClipSpace::OPENGL,
+    );
+    assert_ulps_eq!(frustum.left, Plane::from_abcd(1.0, 0.0, 0.0, 1.0));
+    assert_ulps_eq!(frustum.right, Plane::from_abcd(-1.0, 0.0, 0.0, 1.0));
+    assert_ulps_eq!(frustum.bottom, Plane::from_abcd(0.0, 1.0, 0.0, 1.0));
+    assert_ulps_eq!(frustum.top, Plane::from_abcd(0.0, -1.0, 0.0, 1.0));
+    assert_ulps_eq!(frustum.near, Plane::from_abcd(0.0, 0.0, 1.0, 1.0));
+    assert_ulps_eq!(frustum.far, Plane::from_abcd(0.0, 0.0, -1.0, 1.0));
+
+    let frustum = Frustum::from_matrix4(
+        Matrix4::identity(),
+        PerspectiveMode::Standard,
+        ClipSpace::WGPU,
+    );
+    assert_ulps_eq!(frustum.near, Plane::from_abcd(0.0, 0.0, 1.0, 0.0));
+    assert_ulps_eq!(frustum.far, Plane::from_abcd(0.0, 0.0, -1.0, 1.0));
+}
+
+#[test]
+fn test_perspective_planes() {
+    let frustum = Frustum::from_matrix4(
+        perspective(Deg(90.0f64), 1.0, 1.0, 10.0),
+        PerspectiveMode::Standard,
+        ClipSpace::OPENGL,
+    );
+    let s = 0.5f64.sqrt();
+    assert_relative_eq!(
+        frustum.left,
+        Plane::from_abcd(s, 0.0, -s, 0.0),
+        epsilon = 1e-12
+    );
+    assert_relative_eq!(
+        frustum.top,
+        Plane::from_abcd(0.0, -s, -s, 0.0),
+        epsilon = 1e-12
+    );
+    assert_relative_eq!(
+        frustum.near,
+        Plane::from_abcd(0.0, 0.0, -1.0, -1.0),
+        epsilon = 1e-12
+    );
+    assert_relative_eq!(
+        frustum.far,
+        Plane::from_abcd(0.0, 0.0, 1.0, 10.0),
+        epsilon = 1e-12
+    );
+}
+
+#[test]
+fn test_zero_to_one_depth() {
+    // Remap OpenGL style depth from [-1, 1] to [0, 1].
+    let remap = Matrix4::new(
+        1.0, 0.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 0.0, 0.5, 0.0, 0.0, 0.0, 0.5, 1.0,
+    );
+    let proj = perspective(Deg(60.0f64), 1.5, 0.1, 100.0);
+
+    let expected = Frustum::from_matrix4(proj, PerspectiveMode::Standard, ClipSpace::OPENGL);
+    let frustum = Frustum::from_matrix4(remap * proj, PerspectiveMode::Standard, ClipSpace::WGPU);
+    assert_relative_eq!(frustum, expected, epsilon = 1e-9);
+}
+
+#[test]
+fn test_relate_point() {
+    let view = Matrix4::look_at_rh(
+        Point3::new(0.0, 0.0, 5.0),
+        Point3::new(0.0, 0.0, 0.0),
+        Vector3::unit_y(),
+    );
+    let frustum = Frustum::from_matrix4(
+        perspective(Deg(90.0f64), 1.0, 1.0, 10.0) * view,
+        PerspectiveMode::Standard,
+        ClipSpace::OPENGL,
+    );
+
+    assert_eq!(
+        frustum.relate_point(Point3::new(0.0, 0.0, 0.0)),
+        Relation::Inside
+    );
+    assert_eq!(
+        frustum.relate_point(Point3::new(0.0, 0.0, 4.5)),
+        Relation::Outside
+    );
+    assert_eq!(
+        frustum.relate_point(Point3::new(0.0, 0.0, -6.0)),
+        Relation::Outside
+    );
+    assert_eq!(
+        frustum.relate_point(Point3::new(4.0, 0.0, 0.0)),
+        Relation::Inside
+    );
+    assert_eq!(
+        frustum.relate_point(Point3::new(6.0, 0.0, 0.0)),
+        Relation::Outside
+    );
+    assert!(frustum.contains(Point3::new(0.0, -2.0, 2.0)));
+    assert!(!frustum.contains(Point3::new(0.0, -4.0, 2.0)));
+}
+
+#[test]
+fn test_relate_sphere() {
+    let frustum = Frustum::from_matrix4(
+        perspective(Deg(90.0f64), 1.0, 1.0, 10.0),
+        PerspectiveMode::Standard,
+        ClipSpace::OPENGL,
+    );
+
+    let inside = Sphere::new(Point3::new(0.0, 0.0, -5.0), 1.0);
+    let straddling_far = Sphere::new(Point3::new(0.0, 0.0, -10.0), 1.0);
+    let behind = Sphere::new(Point3::new(0.0, 0.0, 2.0), 1.0);
+    let beside = Sphere::new(Point3::new(8.0, 0.0, -5.0), 1.0);
+
+    assert_eq!(frustum.relate_sphere(inside), Relation::Inside);
+    assert_eq!(
+        frustum.relate_sphere(straddling_far),
+        Relation::Intersecting
+    );
+    assert_eq!(frustum.relate_sphere(behind), Relation::Outside);
+    assert_eq!(frustum.relate_sphere(beside), Relation::Outside);
+}
+
+#[test]
+fn test_relate_aabb() {
+    let frustum = Frustum::from_matrix4(
+        ortho(-2.0f64, 2.0, -1.0, 1.0, 0.0, 10.0),
+        PerspectiveMode::Standard,
+        ClipSpace::OPENGL,
+    );
+
+    let inside = Aabb3::new(Point3::new(-1.0, -0.5, -2.0), Point3::new(1.0, 0.5, -1.0));
+    let straddling = Aabb3::new(Point3::new(1.0, -0.5, -2.0), Point3::new(3.0, 0.5, -1.0));
+    let outside = Aabb3::new(Point3::new(3.0, -0.5, -2.0), Point3::new(4.0, 0.5, -1.0));
+    let enclosing = Aabb3::new(Point3::new(-5.0, -5.0, -20.0), Point3::new(5.0, 5.0, 5.0));
+
+    assert_eq!(frustum.relate_aabb(inside), Relation::Inside);
+    assert_eq!(frustum.relate_aabb(straddling), Relation::Intersecting);
+    assert_eq!(frustum.relate_aabb(outside), Relation::Outside);
+    assert_eq!(frustum.relate_aabb(enclosing), Relation::Intersecting);
+}
+
+#[test]
+fn test_relate_sphere_large_extents() {
+    let frustum = Frustum::from_matrix4(
+        ortho(-2e7f32, 2e7, -2e7, 2e7, 0.0, 10.0),
+        PerspectiveMode::Standard,
+        ClipSpace::OPENGL,
+    );
+
+    let inside = Sphere::new(Point3::new(-1e7, 0.0, -5.0), 1.0);
+    let beside = Sphere::new(Point3::new(-3e7, 0.0, -5.0), 1e6);
+
+    assert_relative_eq!(frustum.left.n.magnitude(), 1.0);
+    assert_eq!(frustum.relate_sphere(inside), Relation::Inside);
+    assert_eq!(frustum.relate_sphere(beside), Relation::Outside);
+}
+
+#[test]
+fn test_reversed_infinite_projection() {
+    let proj = PerspectiveFov {
+        fovy: Deg(90.0f64).into(),
+        aspect: 1.0,
+        near: 1.0,
+        far: 10.0,
+    }
+    .to_matrix_with(PerspectiveMode::ReversedZInfiniteFar, ClipSpace::WGPU);
+    let frustum =
+        Frustum::from_matrix4(proj, PerspectiveMode::ReversedZInfiniteFar, ClipSpace::WGPU);
+
+    assert_relative_eq!(
+        frustum.near,
+        Plane::from_abcd(0.0, 0.0, -1.0, -1.0),
+        epsilon = 1e-12
+    );
+    assert_eq!(frustum.far.n, Vector3::zero());
+    assert_eq!(
+        frustum.relate_point(Point3::new(0.0, 0.0, -0.5)),
+        Relation::Outside
+    );
+    assert_eq!(
+        frustum.relate_point(Point3::new(0.0, 0.0, -1e9)),
+        Relation::Inside
+    );
+}
+
+#[test]
+fn test_clip_space_planes() {
+    let persp = PerspectiveFov {
+        fovy: Deg(90.0f64).into(),
+        aspect: 1.0,
+        near: 1.0,
+        far: 10.0,
+    };
+    let expected = Frustum::from_matrix4(
+        Matrix4::from(persp),
+        PerspectiveMode::Standard,
+        ClipSpace::OPENGL,
+    );
+
+    for &clip in &[ClipSpace::VULKAN, ClipSpace::WGPU] {
+        let proj = persp.to_matrix_with(PerspectiveMode::Standard, clip);
+        assert_relative_eq!(
+            Frustum::from_matrix4(proj, PerspectiveMode::Standard, clip),
+            expected,
+            epsilon = 1e-12
+        );
+    }
+}
+
+#[test]
+fn test_reversed_projection() {
+    let persp = PerspectiveFov {
+        fovy: Deg(90.0f64).into(),
+        aspect: 1.0,
+        near: 1.0,
+        far: 10.0,
+    };
+    let expected = Frustum::from_matrix4(
+        Matrix4::from(persp),
+        PerspectiveMode::Standard,
+        ClipSpace::OPENGL,
+    );
+
+    // Reversed-Z maps depth to `[0, 1]` even for OpenGL clip space.
+    for &clip in &[ClipSpace::OPENGL, ClipSpace::WGPU] {
+        let proj = persp.to_matrix_with(PerspectiveMode::ReversedZ, clip);
+        let frustum = Frustum::from_matrix4(proj, PerspectiveMode::ReversedZ, clip);
+        assert_relative_eq!(frustum, expected, epsilon = 1e-12);
+
+        assert_eq!(
+            frustum.relate_point(Point3::new(0.0, 0.0, -0.5)),
+            Relation::Outside
+        );
+        assert_eq!(
+            frustum.relate_point(Point3::new(0.0, 0.0, -5.0)),
+            Relation::Inside
+        );
+        assert_eq!(
+            frustum.relate_point(Point3::new(0.0, 0.0, -11.0)),
+            Relation::Outside
+        );
+        assert_eq!(
+            frustum.relate_sphere(Sphere::new(Point3::new(0.0, 0.0, -10.0), 1.0)),
+            Relation::Intersecting
+        );
+    }
+}
@@ -0,0 +1,174 @@
+// Copyright 2013-2014 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+extern crate approx;
+extern crate cgmath;
+
+use cgmath::*;
+
+#[test]
+fn test_plane_from_points() {
+    let plane = Plane::from_points(
+        Point3::new(0.0f64, 0.0, 2.0),
+        Point3::new(1.0, 0.0, 2.0),
+        Point3::new(0.0, 1.0, 2.0),
+    )
+    .unwrap();
+    assert_ulps_eq!(plane, Plane::new(Vector3::unit_z(), -2.0));
+    assert_ulps_eq!(plane.distance(Point3::new(3.0, 4.0, 5.0)), 3.0);
+    assert_ulps_eq!(
+        plane.project_point(Point3::new(3.0, 4.0, 5.0)),
+        Point3::new(3.0, 4.0, 2.0)
+    );
+
+    let collinear = Plane::from_points(
+        Point3::new(0.0f64, 0.0, 0.0),
+        Point3::new(1.0, 1.0, 1.0),
+        Point3::new(2.0, 2.0, 2.0),
+    );
+    assert_eq!(collinear, None);
+}
+
+#[test]
+fn test_ray_plane() {
+    let plane = Plane::from_point_normal(Point3::new(0.0f64, 0.0, 5.0), Vector3::unit_z());
+    let ray = Ray3::new(Point3::new(1.0, 2.0, 0.0), Vector3::new(0.0, 0.0, 2.0));
+    assert_ulps_eq!(ray.intersect_plane(plane).unwrap(), 2.5);
+
+    let away = Ray3::new(Point3::new(1.0, 2.0, 0.0), Vector3::new(0.0, 0.0, -1.0));
+    assert_eq!(away.intersect_plane(plane), None);
+
+    let parallel = Ray3::new(Point3::new(1.0, 2.0, 0.0), Vector3::unit_x());
+    assert_eq!(parallel.intersect_plane(plane), None);
+}
+
+#[test]
+fn test_ray_sphere() {
+    let sphere = Sphere::new(Point3::new(0.0f64, 0.0, 10.0), 2.0);
+
+    let ray = Ray3::new(Point3::origin(), Vector3::unit_z());
+    assert_ulps_eq!(ray.intersect_sphere(sphere).unwrap(), 8.0);
+
+    let inside = Ray3::new(Point3::new(0.0, 0.0, 10.0), Vector3::unit_z());
+    assert_ulps_eq!(inside.intersect_sphere(sphere).unwrap(), 2.0);
+
+    let miss = Ray3::new(Point3::new(3.0, 0.0, 0.0), Vector3::unit_z());
+    assert_eq!(miss.intersect_sphere(sphere), None);
+
+    let behind = Ray3::new(Point3::origin(), -Vector3::unit_z());
+    assert_eq!(behind.intersect_sphere(sphere), None);
+}
+
+#[test]
+fn test_ray_triangle() {
+    let triangle = Triangle3::new(
+        Point3::new(0.0f64, 0.0, 1.0),
+        Point3::new(1.0, 0.0, 1.0),
+        Point3::new(0.0, 1.0, 1.0),
+    );
+
+    let ray = Ray3::new(Point3::new(0.25, 0.5, -1.0), Vector3::unit_z());
+    let (t, bary) = ray.intersect_triangle(triangle).unwrap();
+    assert_ulps_eq!(t, 2.0);
+    assert_ulps_eq!(bary, Vector3::new(0.25, 0.25, 0.5));
+    assert_ulps_eq!(triangle.from_barycentric(bary), ray.at(t));
+
+    // Hits from behind are reported too
+    let back = Ray3::new(Point3::new(0.25, 0.5, 3.0), -Vector3::unit_z());
+    assert_ulps_eq!(back.intersect_triangle(triangle).unwrap().0, 2.0);
+
+    let miss = Ray3::new(Point3::new(0.75, 0.75, -1.0), Vector3::unit_z());
+    assert_eq!(miss.intersect_triangle(triangle), None);
+
+    let parallel = Ray3::new(Point3::new(0.0, 0.0, 0.0), Vector3::unit_x());
+    assert_eq!(parallel.intersect_triangle(triangle), None);
+}
+
+#[test]
+fn test_small_triangle() {
+    let triangle = Triangle3::new(
+        Point3::new(0.0f32, 0.0, 1.0),
+        Point3::new(1e-4, 0.0, 1.0),
+        Point3::new(0.0, 1e-4, 1.0),
+    );
+    let ray = Ray3::new(Point3::new(0.25e-4, 0.5e-4, 0.0), Vector3::unit_z());
+    let (t, bary) = ray.intersect_triangle(triangle).unwrap();
+    assert_relative_eq!(t, 1.0);
+    assert_relative_eq!(bary, Vector3::new(0.25, 0.25, 0.5), epsilon = 1e-6);
+    assert_relative_eq!(triangle.normal().unwrap(), Vector3::unit_z());
+    assert_relative_eq!(ray.intersect_plane(triangle.plane().unwrap()).unwrap(), 1.0);
+
+    let triangle = Triangle3::new(
+        Point3::new(0.0f64, 0.0, 1.0),
+        Point3::new(1e-8, 0.0, 1.0),
+        Point3::new(0.0, 1e-8, 1.0),
+    );
+    let ray = Ray3::new(Point3::new(0.25e-8, 0.5e-8, 0.0), Vector3::unit_z() * 1e-8);
+    let (t, bary) = ray.intersect_triangle(triangle).unwrap();
+    assert_relative_eq!(t, 1e8);
+    assert_relative_eq!(bary, Vector3::new(0.25, 0.25, 0.5), epsilon = 1e-12);
+    assert_relative_eq!(triangle.normal().unwrap(), Vector3::unit_z());
+}
+
+#[test]
+fn test_triangle_measures() {
+    let triangle = Triangle3::new(
+        Point3::new(0.0f64, 0.0, 0.0),
+        Point3::new(2.0, 0.0, 0.0),
+        Point3::new(0.0, 2.0, 0.0),
+    );
+    assert_ulps_eq!(triangle.area(), 2.0);
+    assert_ulps_eq!(triangle.normal().unwrap(), Vector3::unit_z());
+    assert_eq!(
+        triangle.aabb(),
+        Aabb3::new(Point3::new(0.0, 0.0, 0.0), Point3::new(2.0, 2.0, 0.0))
+    );
+}
+
+#[test]
+fn test_ray_aabb() {
+    let aabb = Aabb3::new(Point3::new(-1.0f64, -1.0, 2.0), Point3::new(1.0, 1.0, 4.0));
+
+    let ray = Ray3::new(Point3::origin(), Vector3::unit_z());
+    assert_ulps_eq!(ray.intersect_aabb(aabb).unwrap(), 2.0);
+
+    let diagonal = Ray3::new(Point3::new(-3.0, -3.0, 0.0), Vector3::new(1.0, 1.0, 1.0));
+    assert_ulps_eq!(diagonal.intersect_aabb(aabb).unwrap(), 2.0);
+
+    let inside = Ray3::new(Point3::new(0.0, 0.0, 3.0), -Vector3::unit_z());
+    assert_ulps_eq!(inside.intersect_aabb(aabb).unwrap(), 1.0);
+
+    let miss = Ray3::new(Point3::new(2.0, 0.0, 0.0), Vector3::unit_z());
+    assert_eq!(miss.intersect_aabb(aabb), None);
+
+    let behind = Ray3::new(Point3::new(0.0, 0.0, 5.0), Vector3::unit_z());
+    assert_eq!(behind.intersect_aabb(aabb), None);
+}
+
+#[test]
+fn test_sphere() {
+    let a = Sphere::new(Point3::new(0.0f64, 0.0, 0.0), 1.0);
+    let b = Sphere::new(Point3::new(1.5, 0.0, 0.0), 1.0);
+    let c = Sphere::new(Point3::new(3.0, 0.0, 0.0), 0.5);
+
+    assert!(a.intersects(b));
+    assert!(!a.intersects(c));
+    assert!(a.contains(Point3::new(0.5, 0.5, 0.5)));
+    assert!(!a.contains(Point3::new(1.0, 1.0, 0.0)));
+    assert_eq!(
+        b.aabb(),
+        Aabb3::new(Point3::new(0.5, -1.0, -1.0), Point3::new(2.5, 1.0, 1.0))
+    );
+}
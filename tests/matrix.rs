@@ -204,6 +204,59 @@ pub mod matrix2 {
         let rot2 = Matrix2::look_at(-V, Vector2::unit_y());
         assert_eq!(rot2 * Vector2::unit_x(), (-V).normalize());
     }
+
+    #[test]
+    fn test_orthonormalize() {
+        let drifted = Matrix2::from_angle(Deg(40.0)) * Matrix2::new(1.01, 0.02, -0.01, 0.98);
+
+        let m = drifted.orthonormalize_gram_schmidt().unwrap();
+        assert_relative_eq!(m.transpose() * m, Matrix2::identity(), epsilon = 1e-12);
+        assert_relative_eq!(m.x, drifted.x.normalize(), epsilon = 1e-12);
+
+        let m = drifted.orthonormalize_symmetric().unwrap();
+        assert_relative_eq!(m.transpose() * m, Matrix2::identity(), epsilon = 1e-12);
+        assert_relative_eq!(m.determinant(), 1.0, epsilon = 1e-12);
+        assert_relative_eq!(m, Matrix2::from_angle(Deg(40.0)), epsilon = 2e-2);
+
+        let mirrored = Matrix2::new(-2.0, 0.1, 0.0, 1.0);
+        assert_relative_eq!(
+            mirrored.orthonormalize_symmetric().unwrap().determinant(),
+            -1.0,
+            epsilon = 1e-12
+        );
+        assert_eq!(
+            mirrored
+                .orthonormalize_gram_schmidt()
+                .unwrap()
+                .determinant(),
+            -1.0
+        );
+
+        let singular = Matrix2::new(1.0, 2.0, 2.0, 4.0);
+        assert_eq!(singular.orthonormalize_gram_schmidt(), None);
+        assert_eq!(singular.orthonormalize_symmetric(), None);
+
+        let small = Matrix2::<f32>::from_value(1e-8);
+        assert_eq!(
+            small.orthonormalize_gram_schmidt(),
+            Some(Matrix2::identity())
+        );
+        assert_relative_eq!(
+            small.orthonormalize_symmetric().unwrap(),
+            Matrix2::identity()
+        );
+    }
+
+    #[test]
+    fn test_adjugate() {
+        let m = Matrix2::new(1.0f64, 3.0, 2.0, 4.0);
+        assert_eq!(m.adjugate(), Matrix2::new(4.0, -3.0, -2.0, 1.0));
+        assert_eq!(m.cofactor(), m.adjugate().transpose());
+        assert_eq!(m * m.adjugate(), Matrix2::from_value(m.determinant()));
+
+        let singular = Matrix2::new(1.0f64, 2.0, 2.0, 4.0);
+        assert_eq!(singular * singular.adjugate(), Matrix2::zero());
+    }
 }
 
 pub mod matrix3 {
@@ -418,6 +471,40 @@ pub mod matrix3 {
         );
     }
 
+    #[test]
+    fn test_invert_affine() {
+        let m = Matrix3::from_translation(Vector2::new(3.0f64, -2.0))
+            * Matrix3::from(Matrix2::from_angle(Deg(30.0)))
+            * Matrix3::from_nonuniform_scale(2.0, 0.5);
+
+        assert!(m.is_affine());
+        assert!(!Matrix3::new(1.0f64, 0.0, 0.5, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0).is_affine());
+        assert_relative_eq!(
+            m.invert_affine().unwrap(),
+            m.invert().unwrap(),
+            epsilon = 1e-12
+        );
+        assert_relative_eq!(
+            Transform::<Point2<f64>>::inverse_transform(&m).unwrap(),
+            m.invert().unwrap(),
+            epsilon = 1e-12
+        );
+
+        let singular = Matrix3::from_translation(Vector2::new(1.0f64, 1.0))
+            * Matrix3::from_nonuniform_scale(1.0, 0.0);
+        assert_eq!(singular.invert_affine(), None);
+
+        // Tiny projective terms pass `is_affine`, but are not dropped when
+        // inverting.
+        let mut nearly = Matrix3::from_translation(Vector2::new(1000.0f32, 0.0));
+        nearly.x.z = 1e-8;
+        assert!(nearly.is_affine());
+        assert_eq!(
+            Transform::<Point2<f32>>::inverse_transform(&nearly),
+            nearly.invert()
+        );
+    }
+
     #[test]
     fn test_predicates() {
         assert!(Matrix3::<f64>::identity().is_identity());
@@ -763,6 +850,286 @@ pub mod matrix3 {
             ])
         );
     }
+
+    mod symmetric_eigen {
+        use cgmath::*;
+
+        fn check(m: Matrix3<f64>, values: Vector3<f64>) {
+            let eig = m.symmetric_eigen().unwrap();
+            assert_relative_eq!(eig.values, values, epsilon = 1e-10);
+            assert_relative_eq!(eig.recompose(), m, epsilon = 1e-10);
+            assert_relative_eq!(
+                eig.vectors.transpose() * eig.vectors,
+                Matrix3::identity(),
+                epsilon = 1e-12
+            );
+            assert_relative_eq!(eig.vectors.determinant(), 1.0, epsilon = 1e-12);
+            for i in 0..3 {
+                assert_relative_eq!(
+                    m * eig.vectors[i],
+                    eig.vectors[i] * eig.values[i],
+                    epsilon = 1e-10
+                );
+            }
+        }
+
+        #[test]
+        fn test_diagonal() {
+            check(
+                Matrix3::from_diagonal(Vector3::new(1.0, 3.0, 2.0)),
+                Vector3::new(3.0, 2.0, 1.0),
+            );
+        }
+
+        #[test]
+        fn test_rotated() {
+            let r = Matrix3::from_axis_angle(Vector3::new(1.0, -2.0, 0.5).normalize(), Deg(37.0));
+            let m = r * Matrix3::from_diagonal(Vector3::new(5.0, -1.0, 2.0)) * r.transpose();
+            check(m, Vector3::new(5.0, 2.0, -1.0));
+
+            let eig = m.symmetric_eigen().unwrap();
+            let axis = eig.basis().rotate_vector(Vector3::unit_x());
+            assert_relative_eq!(axis.dot(r.x).abs(), 1.0, epsilon = 1e-10);
+        }
+
+        #[test]
+        fn test_repeated_eigenvalues() {
+            let r = Matrix3::from_angle_z(Deg(20.0)) * Matrix3::from_angle_x(Deg(-65.0));
+            let m = r * Matrix3::from_diagonal(Vector3::new(4.0, 1.0, 1.0)) * r.transpose();
+            check(m, Vector3::new(4.0, 1.0, 1.0));
+            check(Matrix3::from_value(2.0), Vector3::new(2.0, 2.0, 2.0));
+            check(Matrix3::zero(), Vector3::zero());
+        }
+
+        #[test]
+        fn test_inertia_tensor() {
+            // Inertia tensor of a solid box with unit mass and sides 1, 2, 3.
+            let d = Vector3::new(2.0 * 2.0 + 3.0 * 3.0, 1.0 + 3.0 * 3.0, 1.0 + 2.0 * 2.0) / 12.0;
+            let r = Matrix3::from_angle_y(Deg(30.0));
+            check(
+                r * Matrix3::from_diagonal(d) * r.transpose(),
+                Vector3::new(d.x, d.y, d.z),
+            );
+        }
+
+        #[test]
+        fn test_not_symmetric() {
+            let m = Matrix3::new(1.0, 2.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0);
+            assert_eq!(m.symmetric_eigen(), None);
+        }
+
+        #[test]
+        fn test_large_entries() {
+            let r = Matrix3::from_angle_x(Deg(40.0));
+            let values = Vector3::new(5e200f64, 2e200, -1e200);
+            let m = r * Matrix3::from_diagonal(values) * r.transpose();
+            let eig = m.symmetric_eigen().unwrap();
+
+            assert_relative_eq!(eig.values, values, max_relative = 1e-12);
+            assert_relative_eq!(eig.vectors.x.dot(r.x).abs(), 1.0, epsilon = 1e-12);
+        }
+    }
+
+    mod svd {
+        use cgmath::*;
+
+        fn is_rotation(r: Basis3<f64>) -> bool {
+            let m: Matrix3<f64> = r.into();
+            relative_eq!(m.transpose() * m, Matrix3::identity(), epsilon = 1e-10)
+                && relative_eq!(m.determinant(), 1.0, epsilon = 1e-10)
+        }
+
+        fn check(m: Matrix3<f64>) -> Svd<f64> {
+            let svd = m.svd().unwrap();
+            assert!(is_rotation(svd.u));
+            assert!(is_rotation(svd.v));
+            assert_relative_eq!(svd.recompose(), m, epsilon = 1e-10);
+
+            let s = svd.singular_values;
+            assert!(s.x >= s.y && s.y >= s.z.abs());
+
+            let polar = m.polar_decompose().unwrap();
+            assert!(is_rotation(polar.rotation));
+            assert!(polar.stretch.is_symmetric());
+            assert_relative_eq!(polar.recompose(), m, epsilon = 1e-10);
+            svd
+        }
+
+        #[test]
+        fn test_general() {
+            let m = Matrix3::new(1.0, 2.0, 3.0, -1.0, 0.5, 4.0, 2.0, 2.0, -3.0);
+            let svd = check(m);
+            assert_relative_eq!(
+                svd.singular_values.product(),
+                m.determinant(),
+                epsilon = 1e-10
+            );
+        }
+
+        #[test]
+        fn test_scaled_rotation() {
+            let r = Basis3::from_axis_angle(Vector3::new(1.0, 1.0, 0.0).normalize(), Deg(70.0));
+            let stretch = Matrix3::from_diagonal(Vector3::new(1.0, 3.0, 2.0));
+            let m = r.as_ref() * stretch;
+            let svd = check(m);
+            assert_relative_eq!(
+                svd.singular_values,
+                Vector3::new(3.0, 2.0, 1.0),
+                epsilon = 1e-10
+            );
+
+            let polar = m.polar_decompose().unwrap();
+            assert_relative_eq!(polar.rotation, r, epsilon = 1e-10);
+            assert_relative_eq!(polar.stretch, stretch, epsilon = 1e-10);
+        }
+
+        #[test]
+        fn test_reflection() {
+            let m = Matrix3::from_diagonal(Vector3::new(2.0, -1.0, 0.5))
+                * Matrix3::from_angle_z(Deg(30.0));
+            let svd = check(m);
+            assert_relative_eq!(
+                svd.singular_values,
+                Vector3::new(2.0, 1.0, -0.5),
+                epsilon = 1e-10
+            );
+        }
+
+        #[test]
+        fn test_rank_deficient() {
+            let rank2 = Matrix3::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0);
+            assert_relative_eq!(check(rank2).singular_values.z, 0.0, epsilon = 1e-10);
+
+            let rank1 = Matrix3::from_cols(
+                Vector3::new(1.0, 2.0, 2.0),
+                Vector3::new(2.0, 4.0, 4.0),
+                Vector3::zero(),
+            );
+            let svd = check(rank1);
+            assert_relative_eq!(
+                svd.singular_values,
+                Vector3::new(3.0 * 5f64.sqrt(), 0.0, 0.0),
+                epsilon = 1e-10
+            );
+
+            let svd = check(Matrix3::zero());
+            assert_eq!(svd.singular_values, Vector3::zero());
+        }
+
+        #[test]
+        fn test_large_entries() {
+            let m = Matrix3::new(1.0, 2.0, 3.0, -1.0, 0.5, 4.0, 2.0, 2.0, -3.0);
+            let svd = m.svd().unwrap();
+            let large = (m * 1e200).svd().unwrap();
+            assert_relative_eq!(
+                large.singular_values,
+                svd.singular_values * 1e200,
+                max_relative = 1e-12
+            );
+            assert_relative_eq!(large.u, svd.u, epsilon = 1e-12);
+            assert_relative_eq!(large.v, svd.v, epsilon = 1e-12);
+
+            let polar = (m * 1e200).polar_decompose().unwrap();
+            assert_relative_eq!(
+                polar.rotation,
+                m.polar_decompose().unwrap().rotation,
+                epsilon = 1e-12
+            );
+        }
+
+        #[test]
+        fn test_not_finite() {
+            assert_eq!(Matrix3::from_value(f64::NAN).svd(), None);
+        }
+    }
+
+    mod orthonormalize {
+        use cgmath::*;
+
+        fn is_orthonormal(m: Matrix3<f64>) -> bool {
+            relative_eq!(m.transpose() * m, Matrix3::identity(), epsilon = 1e-12)
+        }
+
+        #[test]
+        fn test_gram_schmidt() {
+            // A rotation that has drifted after many small steps.
+            let mut m = Matrix3::from_axis_angle(Vector3::new(2.0, -1.0, 2.0) / 3.0, Deg(75.0));
+            let step = Matrix3::from_angle_z(Deg(0.1));
+            for _ in 0..10_000 {
+                m = m * step * 1.000_001;
+            }
+            let o = m.orthonormalize_gram_schmidt().unwrap();
+            assert!(is_orthonormal(o));
+            assert_relative_eq!(o.determinant(), 1.0, epsilon = 1e-12);
+            assert_relative_eq!(o.x, m.x.normalize(), epsilon = 1e-12);
+            assert!(o.y.dot(m.y) > 0.0);
+        }
+
+        #[test]
+        fn test_symmetric() {
+            // A rotation that has drifted after many small steps.
+            let mut m = Matrix3::from_axis_angle(Vector3::new(2.0, -1.0, 2.0) / 3.0, Deg(75.0));
+            let step = Matrix3::from_angle_z(Deg(0.1));
+            for _ in 0..10_000 {
+                m = m * step * 1.000_001;
+            }
+            let o = m.orthonormalize_symmetric().unwrap();
+            assert!(is_orthonormal(o));
+            assert_relative_eq!(o.determinant(), 1.0, epsilon = 1e-12);
+            assert_relative_eq!(
+                o,
+                m.polar_decompose().unwrap().rotation.into(),
+                epsilon = 1e-12
+            );
+
+            let r = Matrix3::from_angle_x(Deg(10.0));
+            assert_relative_eq!(r.orthonormalize_symmetric().unwrap(), r, epsilon = 1e-12);
+        }
+
+        #[test]
+        fn test_reflection() {
+            let m = Matrix3::from_diagonal(Vector3::new(1.0, -1.0, 1.0))
+                * Matrix3::from_angle_y(Deg(30.0))
+                * 2.0;
+            let o = m.orthonormalize_symmetric().unwrap();
+            assert!(is_orthonormal(o));
+            assert_relative_eq!(o, m / 2.0, epsilon = 1e-12);
+            assert_relative_eq!(
+                m.orthonormalize_gram_schmidt().unwrap(),
+                m / 2.0,
+                epsilon = 1e-12
+            );
+        }
+
+        #[test]
+        fn test_singular() {
+            let m = Matrix3::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0);
+            assert_eq!(m.orthonormalize_gram_schmidt(), None);
+            assert_eq!(m.orthonormalize_symmetric(), None);
+        }
+
+        #[test]
+        fn test_small_scale() {
+            let m = Matrix3::<f32>::from_diagonal(vec3(1e-8, 1e-8, 1e-8));
+            assert_eq!(m.orthonormalize_gram_schmidt(), Some(Matrix3::identity()));
+            assert_relative_eq!(m.orthonormalize_symmetric().unwrap(), Matrix3::identity());
+        }
+    }
+
+    #[test]
+    fn test_adjugate() {
+        let m = Matrix3::new(2.0f64, 0.0, 1.0, -1.0, 3.0, 0.5, 0.0, 1.0, 4.0);
+        assert_relative_eq!(
+            m.adjugate(),
+            m.invert().unwrap() * m.determinant(),
+            epsilon = 1e-12
+        );
+        assert_eq!(m.cofactor(), m.adjugate().transpose());
+        assert_eq!(m.cofactor().x, m.y.cross(m.z));
+
+        let singular = Matrix3::new(1.0f64, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0);
+        assert_eq!(singular * singular.adjugate(), Matrix3::zero());
+    }
 }
 
 pub mod matrix4 {
@@ -1111,6 +1478,43 @@ pub mod matrix4 {
         assert!((mat_f.invert().unwrap() * mat_f).is_identity());
     }
 
+    #[test]
+    fn test_invert_affine() {
+        let m = Matrix4::from_translation(Vector3::new(3.0f64, -2.0, 1.0))
+            * Matrix4::from_axis_angle(Vector3::new(1.0, 2.0, 2.0).normalize(), Deg(40.0))
+            * Matrix4::from_nonuniform_scale(2.0, 0.5, -1.5);
+
+        assert!(m.is_affine());
+        assert_relative_eq!(
+            m.invert_affine().unwrap(),
+            m.invert().unwrap(),
+            epsilon = 1e-12
+        );
+        assert_relative_eq!(
+            m.inverse_transform().unwrap(),
+            m.invert().unwrap(),
+            epsilon = 1e-12
+        );
+
+        let projection = perspective(Deg(60.0f64), 1.0, 0.1, 10.0);
+        assert!(!projection.is_affine());
+        assert_relative_eq!(
+            projection.inverse_transform().unwrap(),
+            projection.invert().unwrap(),
+            epsilon = 1e-12
+        );
+
+        let singular = Matrix4::from_nonuniform_scale(1.0f64, 0.0, 1.0);
+        assert_eq!(singular.invert_affine(), None);
+
+        // Tiny projective terms pass `is_affine`, but are not dropped when
+        // inverting.
+        let mut nearly = Matrix4::from_translation(Vector3::new(0.0f32, 0.0, 1000.0));
+        nearly.z.w = 1e-8;
+        assert!(nearly.is_affine());
+        assert_eq!(nearly.inverse_transform(), nearly.invert());
+    }
+
     #[test]
     fn test_predicates() {
         assert!(Matrix4::<f64>::identity().is_identity());
@@ -1223,4 +1627,507 @@ pub mod matrix4 {
             assert_ulps_eq!(matrix_short, matrix_long);
         }
     }
+
+    mod decompose {
+        use cgmath::*;
+
+        #[test]
+        fn test_trs() {
+            let t = Trs {
+                scale: Vector3::new(2.0, 0.5, 3.0),
+                rot: Quaternion::from_axis_angle(
+                    Vector3::new(1.0, 2.0, -1.0).normalize(),
+                    Deg(70.0),
+                ),
+                disp: Vector3::new(1.0, -2.0, 3.0),
+            };
+            let dec = Matrix4::from(t).decompose().unwrap();
+
+            assert_relative_eq!(dec.to_trs(), t, epsilon = 1e-12);
+            assert!(!dec.has_shear());
+            assert!(!dec.is_reflection());
+        }
+
+        #[test]
+        fn test_reflection() {
+            let r =
+                Quaternion::from_axis_angle(Vector3::new(1.0, 2.0, -1.0).normalize(), Deg(70.0));
+            let m = Matrix4::from(r) * Matrix4::from_nonuniform_scale(2.0, -1.0, 3.0);
+            let dec = m.decompose().unwrap();
+
+            assert!(dec.is_reflection());
+            assert!(dec.scale.x < 0.0);
+            assert!(!dec.has_shear());
+            assert_relative_eq!(Matrix4::from(dec), m, epsilon = 1e-12);
+            assert_relative_eq!(Matrix3::from(dec.rot).determinant(), 1.0, epsilon = 1e-12);
+        }
+
+        #[test]
+        fn test_shear() {
+            let r =
+                Quaternion::from_axis_angle(Vector3::new(1.0, 2.0, -1.0).normalize(), Deg(70.0));
+            #[cfg_attr(rustfmt, rustfmt_skip)]
+            let shear = Matrix4::new(
+                1.0, 0.0, 0.0, 0.0,
+                0.5, 1.0, 0.0, 0.0,
+                0.25, -0.75, 1.0, 0.0,
+                0.0, 0.0, 0.0, 1.0,
+            );
+            let m = Matrix4::from_translation(Vector3::new(4.0, 5.0, 6.0))
+                * Matrix4::from(r)
+                * shear
+                * Matrix4::from_nonuniform_scale(2.0, 3.0, 4.0);
+            let dec = m.decompose().unwrap();
+
+            assert!(dec.has_shear());
+            assert_relative_eq!(dec.shear, Vector3::new(0.5, 0.25, -0.75), epsilon = 1e-12);
+            assert_relative_eq!(dec.scale, Vector3::new(2.0, 3.0, 4.0), epsilon = 1e-12);
+            assert_relative_eq!(dec.disp, Vector3::new(4.0, 5.0, 6.0), epsilon = 1e-12);
+            assert_relative_eq!(Matrix4::from(dec), m, epsilon = 1e-12);
+        }
+
+        #[test]
+        fn test_invalid() {
+            assert_eq!(
+                Matrix4::from_nonuniform_scale(1.0, 0.0, 1.0).decompose(),
+                None
+            );
+            assert_eq!(perspective(Deg(60.0), 1.0, 0.1, 10.0).decompose(), None);
+            assert_eq!(Matrix3::<f64>::zero().decompose(), None);
+
+            // Columns that are linearly dependent.
+            let m = Matrix3::new(1.0, 2.0, 3.0, 2.0, 4.0, 6.0, 0.0, 0.0, 1.0);
+            assert_eq!(m.decompose(), None);
+
+            // The test is relative to the size of the columns.
+            let m = Matrix3::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0) * 1e6;
+            assert_eq!(m.decompose(), None);
+            assert_eq!(Matrix4::from(m).decompose(), None);
+            assert!((Matrix3::from_angle_z(Deg(30.0)) * 1e-9)
+                .decompose()
+                .is_some());
+        }
+
+        #[test]
+        fn test_matrix3() {
+            let r =
+                Quaternion::from_axis_angle(Vector3::new(1.0, 2.0, -1.0).normalize(), Deg(70.0));
+            let m = Matrix3::from(r) * Matrix3::from_diagonal(Vector3::new(1.0, 2.0, 3.0));
+            let dec = m.decompose().unwrap();
+
+            assert_relative_eq!(dec.rot, r, epsilon = 1e-12);
+            assert_relative_eq!(dec.scale, Vector3::new(1.0, 2.0, 3.0), epsilon = 1e-12);
+            assert_eq!(dec.disp, Vector3::zero());
+        }
+    }
+
+    #[test]
+    fn test_adjugate() {
+        let m = Matrix4::from_translation(Vector3::new(1.0, 2.0, 3.0))
+            * Matrix4::from_angle_z(Deg(30.0))
+            * Matrix4::from_nonuniform_scale(2.0, 3.0, 0.5);
+        assert_relative_eq!(
+            m.adjugate(),
+            m.invert().unwrap() * m.determinant(),
+            epsilon = 1e-12
+        );
+        assert_eq!(m.cofactor(), m.adjugate().transpose());
+
+        let singular = Matrix4::from_nonuniform_scale(1.0, 0.0, 2.0);
+        assert_eq!(singular * singular.adjugate(), Matrix4::zero());
+    }
+
+    #[test]
+    fn test_normal_matrix() {
+        let m = Matrix4::from_translation(Vector3::new(5.0, 0.0, 0.0))
+            * Matrix4::from_angle_x(Deg(45.0))
+            * Matrix4::from_nonuniform_scale(1.0, 4.0, 2.0);
+        let linear = Matrix3::from_cols(m.x.truncate(), m.y.truncate(), m.z.truncate());
+        let normal = m.normal_matrix();
+        assert_relative_eq!(
+            normal,
+            linear.invert().unwrap().transpose() * linear.determinant(),
+            epsilon = 1e-12
+        );
+
+        // Normals stay perpendicular to the transformed tangents.
+        let (a, b) = (Vector3::new(1.0, 1.0, 0.0), Vector3::new(0.0, 1.0, -1.0));
+        let n = normal * a.cross(b);
+        assert_relative_eq!(n.dot(linear * a), 0.0, epsilon = 1e-12);
+        assert_relative_eq!(n.dot(linear * b), 0.0, epsilon = 1e-12);
+        assert_relative_eq!(n, (linear * a).cross(linear * b), epsilon = 1e-12);
+
+        // Flattening onto the XY plane maps every normal onto the Z axis.
+        let flatten = Matrix4::from_nonuniform_scale(2.0, 3.0, 0.0);
+        let n = flatten.normal_matrix() * Vector3::new(0.3, -0.4, 0.5);
+        assert_eq!(n, Vector3::new(0.0, 0.0, 3.0));
+    }
+}
+
+pub mod rectangular {
+    use cgmath::*;
+
+    const A: Matrix2x3<f64> = Matrix2x3::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0);
+    const B: Matrix3x4<f64> =
+        Matrix3x4::new(1.0, 0.0, 2.0, -1.0, 3.0, 0.5, 0.0, 1.0, 1.0, 4.0, -2.0, 3.0);
+    const C: Matrix4x2<f64> = Matrix4x2::new(2.0, 1.0, 0.0, -1.0, 3.0, 0.5, 1.0, 2.0);
+
+    #[test]
+    fn test_index_and_rows() {
+        assert_eq!(A[2][1], 6.0);
+        assert_eq!(A.row(0), Vector3::new(1.0, 3.0, 5.0));
+        assert_eq!(A.row(1), Vector3::new(2.0, 4.0, 6.0));
+        assert_eq!(B.w, Vector3::new(4.0, -2.0, 3.0));
+    }
+
+    #[test]
+    fn test_transpose() {
+        let t: Matrix3x2<f64> = A.transpose();
+        assert_eq!(t, Matrix3x2::new(1.0, 3.0, 5.0, 2.0, 4.0, 6.0));
+        assert_eq!(t.transpose(), A);
+        assert_eq!(B.transpose().transpose(), B);
+        assert_eq!(C.transpose().row(1), C.y);
+    }
+
+    #[test]
+    fn test_mul_vector() {
+        assert_eq!(A * Vector3::new(1.0, 1.0, 1.0), Vector2::new(9.0, 12.0));
+        assert_eq!(
+            A.transpose() * Vector2::new(1.0, -1.0),
+            Vector3::new(-1.0, -1.0, -1.0)
+        );
+    }
+
+    #[test]
+    fn test_mul_matrix() {
+        let m: Matrix3<f64> = A.transpose() * A;
+        assert!(m.is_symmetric());
+        assert_eq!(
+            m,
+            Matrix3::new(5.0, 11.0, 17.0, 11.0, 25.0, 39.0, 17.0, 39.0, 61.0)
+        );
+
+        let abc: Matrix2<f64> = A * B * C;
+        assert_relative_eq!(abc, A * (B * C), epsilon = 1e-12);
+        assert_relative_eq!((A * B).transpose(), B.transpose() * A.transpose());
+        assert_eq!(B * Matrix4::identity(), B);
+        assert_eq!(Matrix3::identity() * B, B);
+    }
+
+    #[test]
+    fn test_skinning_matrix() {
+        let m = Matrix4::from_translation(Vector3::new(1.0, 2.0, 3.0))
+            * Matrix4::from_angle_y(Deg(30.0));
+        let rows = m.transpose();
+        let skin = Matrix4x3::from_cols(rows.x, rows.y, rows.z).transpose();
+        let p = Point3::new(0.5, -1.0, 2.0);
+
+        assert_relative_eq!(
+            skin * p.to_homogeneous(),
+            m.transform_point(p).to_vec(),
+            epsilon = 1e-12
+        );
+    }
+
+    #[test]
+    fn test_vector_space() {
+        assert_eq!(A + A, A * 2.0);
+        assert_eq!(A - A, Matrix2x3::zero());
+        assert_eq!(-A, A * -1.0);
+        assert_eq!(2.0 * C, C + C);
+        assert_eq!([A, A, A].iter().sum::<Matrix2x3<f64>>(), A * 3.0);
+    }
+
+    #[test]
+    fn test_array_conversions() {
+        let a: [[f64; 2]; 3] = A.into();
+        assert_eq!(a, [[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]]);
+        assert_eq!(Matrix2x3::from(a), A);
+
+        let flat: &[f64; 12] = B.as_ref();
+        assert_eq!(flat[3..6], [-1.0, 3.0, 0.5]);
+        assert_eq!(<&Matrix3x4<f64>>::from(flat), &B);
+    }
+}
+
+pub mod linear_solve {
+    use cgmath::*;
+
+    const A: Matrix4<f64> = Matrix4 {
+        x: Vector4 {
+            x: 0.0f64,
+            y: 2.0f64,
+            z: 1.0f64,
+            w: 4.0f64,
+        },
+        y: Vector4 {
+            x: 3.0f64,
+            y: -1.0f64,
+            z: 0.5f64,
+            w: 2.0f64,
+        },
+        z: Vector4 {
+            x: 1.0f64,
+            y: 1.0f64,
+            z: 6.0f64,
+            w: -2.0f64,
+        },
+        w: Vector4 {
+            x: 2.0f64,
+            y: 0.0f64,
+            z: 1.0f64,
+            w: 1.0f64,
+        },
+    };
+
+    #[test]
+    fn test_lu() {
+        let m = A;
+        let lu = m.lu();
+        assert_relative_eq!(lu.p * m, lu.l * lu.u, epsilon = 1e-12);
+        assert_relative_eq!(lu.determinant(), m.determinant(), epsilon = 1e-12);
+        for c in 0..4 {
+            assert_eq!(lu.l[c][c], 1.0);
+            for r in 0..c {
+                assert_eq!(lu.l[c][r], 0.0);
+            }
+            for r in (c + 1)..4 {
+                assert_eq!(lu.u[c][r], 0.0);
+                assert!(lu.l[c][r].abs() <= 1.0);
+            }
+        }
+    }
+
+    #[test]
+    fn test_qr() {
+        let m = A;
+        let qr = m.qr();
+        assert_relative_eq!(qr.q * qr.r, m, epsilon = 1e-12);
+        assert_relative_eq!(
+            qr.q.transpose() * qr.q,
+            Matrix4::identity(),
+            epsilon = 1e-12
+        );
+        for c in 0..4 {
+            for r in (c + 1)..4 {
+                assert_eq!(qr.r[c][r], 0.0);
+            }
+        }
+
+        let m = Matrix3::new(1.0, 2.0, 2.0, 0.0, 1.0, 0.0, 3.0, 0.0, 1.0);
+        let qr = m.qr();
+        assert_relative_eq!(qr.q * qr.r, m, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn test_solve() {
+        let m = A;
+        let x = Vector4::new(1.0, -2.0, 0.5, 3.0);
+        let b = m * x;
+        assert_relative_eq!(m.solve(b).unwrap(), x, epsilon = 1e-12);
+        assert_relative_eq!(m.qr().solve(b).unwrap(), x, epsilon = 1e-12);
+
+        // Needs a row swap, as the first pivot is zero.
+        let m = Matrix2::new(0.0, 1.0, 1.0, 0.0);
+        assert_eq!(
+            m.solve(Vector2::new(2.0, 3.0)),
+            Some(Vector2::new(3.0, 2.0))
+        );
+
+        let m =
+            Matrix3::from_angle_x(Deg(20.0)) * Matrix3::from_diagonal(Vector3::new(1.0, 2.0, 4.0));
+        let b = Vector3::new(1.0, 1.0, 1.0);
+        assert_relative_eq!(
+            m.solve(b).unwrap(),
+            m.invert().unwrap() * b,
+            epsilon = 1e-12
+        );
+    }
+
+    #[test]
+    fn test_singular() {
+        let m = Matrix3::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0);
+        assert_eq!(m.solve(Vector3::new(1.0, 0.0, 0.0)), None);
+        assert_eq!(m.qr().solve(Vector3::new(1.0, 0.0, 0.0)), None);
+        assert_eq!(m.condition_number(), f64::INFINITY);
+        assert_eq!(Matrix2::<f64>::zero().solve(Vector2::unit_x()), None);
+    }
+
+    #[test]
+    fn test_small_scale() {
+        let m = Matrix3::<f32>::from_diagonal(vec3(1e-8, 1e-8, 1e-8));
+        let b = vec3(1e-8, 2e-8, 3e-8);
+        assert_relative_eq!(m.solve(b).unwrap(), vec3(1.0, 2.0, 3.0));
+        assert_relative_eq!(m.qr().solve(b).unwrap(), vec3(1.0, 2.0, 3.0));
+        assert_relative_eq!(m.condition_number(), 1.0);
+
+        let m = Matrix3::<f64>::from_diagonal(vec3(1e-20, 1e-20, 1e-20));
+        let b = vec3(1e-20, 2e-20, 3e-20);
+        assert_relative_eq!(m.solve(b).unwrap(), vec3(1.0, 2.0, 3.0));
+        assert_relative_eq!(m.qr().solve(b).unwrap(), vec3(1.0, 2.0, 3.0));
+        assert_relative_eq!(m.condition_number(), 1.0);
+    }
+
+    #[test]
+    fn test_condition_number() {
+        assert_eq!(Matrix4::<f64>::identity().condition_number(), 1.0);
+        let m =
+            Matrix3::from_angle_z(Deg(45.0)) * Matrix3::from_diagonal(Vector3::new(1.0, 1.0, 1e-8));
+        assert_relative_eq!(
+            m.condition_number(),
+            1e8 * 2.0f64.sqrt(),
+            max_relative = 1e-6
+        );
+        assert_relative_eq!(Matrix2::new(2.0, 0.0, 0.0, 0.5).condition_number(), 4.0);
+    }
+
+    #[test]
+    fn test_pseudo_inverse() {
+        let m = A;
+        assert_relative_eq!(m.pseudo_inverse(), m.invert().unwrap(), epsilon = 1e-12);
+
+        // The Penrose conditions hold for rank-deficient matrices.
+        let m = Matrix3::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0);
+        let p = m.pseudo_inverse();
+        assert_relative_eq!(m * p * m, m, epsilon = 1e-12);
+        assert_relative_eq!(p * m * p, p, epsilon = 1e-12);
+        assert_relative_eq!((m * p).transpose(), m * p, epsilon = 1e-12);
+        assert_relative_eq!((p * m).transpose(), p * m, epsilon = 1e-12);
+
+        // Least-squares solution for a matrix that only reaches the X axis.
+        let m = Matrix2::new(2.0, 0.0, 4.0, 0.0);
+        assert_relative_eq!(
+            m.pseudo_inverse() * Vector2::new(10.0, 3.0),
+            Vector2::new(1.0, 2.0),
+            epsilon = 1e-12
+        );
+
+        // It matches the singular value decomposition.
+        let m = Matrix3::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0);
+        let svd = m.svd().unwrap();
+        let u: Matrix3<f64> = svd.u.into();
+        let v: Matrix3<f64> = svd.v.into();
+        let inverse_values = svd
+            .singular_values
+            .map(|s| if s.abs() > 1e-12 { 1.0 / s } else { 0.0 });
+        assert_relative_eq!(
+            m.pseudo_inverse(),
+            v * Matrix3::from_diagonal(inverse_values) * u.transpose(),
+            epsilon = 1e-12
+        );
+
+        assert_eq!(Matrix4::<f64>::zero().pseudo_inverse(), Matrix4::zero());
+        assert_relative_eq!(
+            (m * 1e200).pseudo_inverse() * 1e200,
+            m.pseudo_inverse(),
+            epsilon = 1e-12
+        );
+    }
+}
+
+pub mod exp_ln {
+    use cgmath::*;
+
+    #[test]
+    fn test_exp() {
+        assert_eq!(Matrix3::<f64>::zero().exp(), Matrix3::identity());
+        assert_relative_eq!(
+            Matrix3::from_diagonal(Vector3::new(1.0, -2.0, 3.0)).exp(),
+            Matrix3::from_diagonal(Vector3::new(1.0f64.exp(), (-2.0f64).exp(), 3.0f64.exp())),
+            max_relative = 1e-14
+        );
+
+        // The exponential of a skew-symmetric matrix is a rotation.
+        let axis = Vector3::new(1.0, 2.0, -2.0) / 3.0;
+        let angle = 2.5;
+        let skew = Matrix3::new(
+            0.0, axis.z, -axis.y, -axis.z, 0.0, axis.x, axis.y, -axis.x, 0.0,
+        ) * angle;
+        assert_relative_eq!(
+            skew.exp(),
+            Matrix3::from_axis_angle(axis, Rad(angle)),
+            epsilon = 1e-13
+        );
+
+        // A pure translation is nilpotent.
+        let mut m = Matrix4::zero();
+        m.w = Vector4::new(1.0, 2.0, 3.0, 0.0);
+        assert_eq!(
+            m.exp(),
+            Matrix4::from_translation(Vector3::new(1.0, 2.0, 3.0))
+        );
+    }
+
+    #[test]
+    fn test_exp_not_finite() {
+        let mut m = Matrix3::<f64>::identity();
+        m.y.x = f64::INFINITY;
+        assert!(m.exp().x.x.is_nan());
+        assert!(m.exp().z.z.is_nan());
+
+        let mut m = Matrix4::<f32>::zero();
+        m.w.z = f32::NAN;
+        assert!(m.exp()[0][0].is_nan());
+    }
+
+    #[test]
+    fn test_ln() {
+        let r = Matrix3::from_angle_z(Deg(90.0));
+        let log = r.ln().unwrap();
+        let expected = Matrix3::new(0.0, 1.0, 0.0, -1.0, 0.0, 0.0, 0.0, 0.0, 0.0)
+            * std::f64::consts::FRAC_PI_2;
+        assert_relative_eq!(log, expected, epsilon = 1e-13);
+
+        let m = Matrix4::from_translation(Vector3::new(-3.0, 0.5, 2.0))
+            * Matrix4::from_axis_angle(Vector3::new(0.0, 0.6, 0.8), Deg(170.0))
+            * Matrix4::from_scale(10.0);
+        let log = m.ln().unwrap();
+        assert_eq!(log.row(3), Vector4::zero());
+        assert_relative_eq!(log.exp(), m, epsilon = 1e-12);
+
+        let m = Matrix4::from_translation(Vector3::new(1.0, 2.0, 3.0))
+            * Matrix4::from_angle_x(Deg(40.0))
+            * Matrix4::from_nonuniform_scale(0.25, 2.0, 10.0);
+        assert_relative_eq!(m.ln().unwrap().exp(), m, epsilon = 1e-12);
+
+        let m = Matrix3::new(2.0, 1.0, 0.0, 0.5, 3.0, 1.0, 0.0, -1.0, 1.5);
+        assert_relative_eq!(m.ln().unwrap().exp(), m, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn test_ln_none() {
+        assert_eq!(Matrix3::from_nonuniform_scale(-1.0, 1.0).ln(), None);
+        assert_eq!(Matrix4::from_scale(-1.0).ln(), None);
+        assert_eq!(Matrix3::<f64>::zero().ln(), None);
+        assert_eq!(Matrix3::from_value(f64::NAN).ln(), None);
+    }
+
+    #[test]
+    fn test_log_lerp() {
+        let a = Matrix4::from_translation(Vector3::new(1.0, 0.0, 0.0));
+        let b = Matrix4::from_translation(Vector3::new(0.0, 4.0, 0.0))
+            * Matrix4::from_angle_z(Deg(90.0));
+        assert_relative_eq!(a.log_lerp(b, 0.0).unwrap(), a, epsilon = 1e-12);
+        assert_relative_eq!(a.log_lerp(b, 1.0).unwrap(), b, epsilon = 1e-12);
+
+        let r = Matrix3::from_angle_y(Deg(120.0));
+        let half = Matrix3::identity().log_lerp(r, 0.5).unwrap();
+        assert_relative_eq!(half, Matrix3::from_angle_y(Deg(60.0)), epsilon = 1e-12);
+
+        let s = Matrix3::from_scale(4.0);
+        let half = Matrix3::identity().log_lerp(s, 0.5).unwrap();
+        assert_relative_eq!(half, Matrix3::from_scale(2.0), epsilon = 1e-12);
+    }
+
+    #[test]
+    fn test_linear_combination() {
+        let a = Matrix3::from_angle_x(Deg(30.0));
+        let b = Matrix3::from_value(2.0);
+        let blend = Matrix3::linear_combination(vec![(2.0, a), (1.0, b)]).unwrap();
+        assert_relative_eq!(
+            blend,
+            Matrix3::from_angle_x(Deg(60.0)) * 2.0,
+            epsilon = 1e-12
+        );
+    }
 }
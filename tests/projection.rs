@@ -13,9 +13,21 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+extern crate approx;
 extern crate cgmath;
 
-use cgmath::{ortho, Matrix4, Vector4};
+use cgmath::{
+    assert_relative_eq, frustum, frustum_with, ortho, ortho_with, perspective, perspective_with,
+    planar, planar_with, ClipSpace, Deg, Matrix4, Ortho, Perspective, PerspectiveFov,
+    PerspectiveMode, PlanarFov, Vector4,
+};
+
+/// The normalized device depth of a point on the view axis, `distance` units
+/// in front of the camera.
+fn ndc_depth(m: Matrix4<f64>, distance: f64) -> f64 {
+    let clip = m * Vector4::new(0.0, 0.0, -distance, 1.0);
+    clip.z / clip.w
+}
 
 #[test]
 fn test_ortho_scale() {
@@ -66,3 +78,240 @@ fn test_ortho_translate() {
     let orig = o * vec_orig;
     assert_eq!(orig, Vector4::new(1., 1., 1., 1.));
 }
+
+#[test]
+fn test_perspective_modes() {
+    let persp = PerspectiveFov {
+        fovy: Deg(60.0).into(),
+        aspect: 1.5,
+        near: 0.1,
+        far: 100.0,
+    };
+
+    let m = persp.to_matrix(PerspectiveMode::Standard);
+    assert_eq!(m, Matrix4::from(persp));
+    assert_relative_eq!(ndc_depth(m, 0.1), -1.0, epsilon = 1e-12);
+    assert_relative_eq!(ndc_depth(m, 100.0), 1.0, epsilon = 1e-12);
+
+    let m = persp.to_matrix(PerspectiveMode::ReversedZ);
+    assert_relative_eq!(ndc_depth(m, 0.1), 1.0, epsilon = 1e-12);
+    assert_relative_eq!(ndc_depth(m, 100.0), 0.0, epsilon = 1e-12);
+    assert!(ndc_depth(m, 1.0) > ndc_depth(m, 2.0));
+
+    let m = persp.to_matrix(PerspectiveMode::InfiniteFar);
+    assert_relative_eq!(ndc_depth(m, 0.1), -1.0, epsilon = 1e-12);
+    assert!(ndc_depth(m, 1e6) < 1.0);
+    assert_relative_eq!(ndc_depth(m, 1e12), 1.0, epsilon = 1e-9);
+
+    let m = persp.to_matrix(PerspectiveMode::ReversedZInfiniteFar);
+    assert_relative_eq!(ndc_depth(m, 0.1), 1.0, epsilon = 1e-12);
+    assert!(ndc_depth(m, 1e6) > 0.0);
+    assert_relative_eq!(ndc_depth(m, 1e12), 0.0, epsilon = 1e-9);
+}
+
+#[test]
+fn test_perspective_modes_match_fov() {
+    let fov = PerspectiveFov {
+        fovy: Deg(75.0).into(),
+        aspect: 0.8,
+        near: 0.5,
+        far: 50.0,
+    };
+    let persp: Perspective<f64> = fov.to_perspective();
+
+    for &mode in &[
+        PerspectiveMode::Standard,
+        PerspectiveMode::InfiniteFar,
+        PerspectiveMode::ReversedZ,
+        PerspectiveMode::ReversedZInfiniteFar,
+    ] {
+        assert_relative_eq!(persp.to_matrix(mode), fov.to_matrix(mode), epsilon = 1e-12);
+    }
+}
+
+#[test]
+fn test_infinite_far_ignores_far() {
+    let mut persp = Perspective {
+        left: -1.0,
+        right: 1.0,
+        bottom: -1.0,
+        top: 1.0,
+        near: 1.0,
+        far: 10.0,
+    };
+    let m = persp.to_matrix(PerspectiveMode::ReversedZInfiniteFar);
+
+    persp.far = 0.0;
+    assert_eq!(persp.to_matrix(PerspectiveMode::ReversedZInfiniteFar), m);
+}
+
+/// Maps OpenGL clip space to Vulkan clip space.
+#[cfg_attr(rustfmt, rustfmt_skip)]
+const GL_TO_VULKAN: Matrix4<f64> = Matrix4::new(
+    1.0,  0.0, 0.0, 0.0,
+    0.0, -1.0, 0.0, 0.0,
+    0.0,  0.0, 0.5, 0.0,
+    0.0,  0.0, 0.5, 1.0,
+);
+
+#[test]
+fn test_clip_space_default() {
+    assert_eq!(ClipSpace::default(), ClipSpace::OPENGL);
+
+    let persp = PerspectiveFov {
+        fovy: Deg(60.0).into(),
+        aspect: 1.5,
+        near: 0.1,
+        far: 100.0,
+    };
+    assert_eq!(
+        persp.to_matrix_with(PerspectiveMode::Standard, ClipSpace::default()),
+        perspective(Deg(60.0), 1.5, 0.1, 100.0)
+    );
+}
+
+#[test]
+fn test_clip_space_vulkan() {
+    let persp = PerspectiveFov {
+        fovy: Deg(60.0).into(),
+        aspect: 1.5,
+        near: 0.1,
+        far: 100.0,
+    };
+    assert_relative_eq!(
+        persp.to_matrix_with(PerspectiveMode::Standard, ClipSpace::VULKAN),
+        GL_TO_VULKAN * Matrix4::from(persp),
+        epsilon = 1e-12
+    );
+    assert_relative_eq!(
+        persp.to_matrix_with(PerspectiveMode::InfiniteFar, ClipSpace::VULKAN),
+        GL_TO_VULKAN * persp.to_matrix(PerspectiveMode::InfiniteFar),
+        epsilon = 1e-12
+    );
+
+    let frustum = persp.to_perspective();
+    assert_relative_eq!(
+        frustum.to_matrix_with(PerspectiveMode::Standard, ClipSpace::VULKAN),
+        GL_TO_VULKAN * Matrix4::from(frustum),
+        epsilon = 1e-12
+    );
+
+    let o = Ortho {
+        left: -2.0,
+        right: 3.0,
+        bottom: -1.0,
+        top: 4.0,
+        near: 0.5,
+        far: 20.0,
+    };
+    assert_relative_eq!(
+        o.to_matrix_with(ClipSpace::VULKAN),
+        GL_TO_VULKAN * Matrix4::from(o),
+        epsilon = 1e-12
+    );
+
+    let p = PlanarFov {
+        fovy: Deg(30.0).into(),
+        aspect: 1.5,
+        height: 2.0,
+        near: 0.5,
+        far: 20.0,
+    };
+    assert_relative_eq!(
+        p.to_matrix_with(ClipSpace::VULKAN),
+        GL_TO_VULKAN * Matrix4::from(p),
+        epsilon = 1e-12
+    );
+}
+
+#[test]
+fn test_clip_space_functions() {
+    assert_relative_eq!(
+        perspective_with(Deg(60.0), 1.5, 0.1, 100.0, ClipSpace::VULKAN),
+        GL_TO_VULKAN * perspective(Deg(60.0), 1.5, 0.1, 100.0),
+        epsilon = 1e-12
+    );
+    assert_relative_eq!(
+        frustum_with(-2.0, 3.0, -1.0, 4.0, 0.5, 20.0, ClipSpace::VULKAN),
+        GL_TO_VULKAN * frustum(-2.0, 3.0, -1.0, 4.0, 0.5, 20.0),
+        epsilon = 1e-12
+    );
+    assert_relative_eq!(
+        ortho_with(-2.0, 3.0, -1.0, 4.0, 0.5, 20.0, ClipSpace::VULKAN),
+        GL_TO_VULKAN * ortho(-2.0, 3.0, -1.0, 4.0, 0.5, 20.0),
+        epsilon = 1e-12
+    );
+    assert_relative_eq!(
+        planar_with(Deg(30.0), 1.5, 2.0, 0.5, 20.0, ClipSpace::VULKAN),
+        GL_TO_VULKAN * planar(Deg(30.0), 1.5, 2.0, 0.5, 20.0),
+        epsilon = 1e-12
+    );
+    assert_eq!(
+        ortho_with(-2.0, 3.0, -1.0, 4.0, 0.5, 20.0, ClipSpace::OPENGL),
+        ortho(-2.0, 3.0, -1.0, 4.0, 0.5, 20.0)
+    );
+}
+
+#[test]
+fn test_clip_space_left_handed() {
+    let persp = PerspectiveFov {
+        fovy: Deg(60.0).into(),
+        aspect: 1.5,
+        near: 0.1,
+        far: 100.0,
+    };
+    let m = persp.to_matrix_with(PerspectiveMode::Standard, ClipSpace::DIRECT3D);
+
+    // The camera looks down the positive Z axis.
+    let near = m * Vector4::new(0.0, 0.0, 0.1, 1.0);
+    let far = m * Vector4::new(0.0, 0.0, 100.0, 1.0);
+    assert_relative_eq!(near.z / near.w, 0.0, epsilon = 1e-12);
+    assert_relative_eq!(far.z / far.w, 1.0, epsilon = 1e-12);
+
+    let p = m * Vector4::new(1.0, 2.0, 5.0, 1.0);
+    let q = persp.to_matrix_with(PerspectiveMode::Standard, ClipSpace::WGPU)
+        * Vector4::new(1.0, 2.0, -5.0, 1.0);
+    assert_relative_eq!(p, q, epsilon = 1e-12);
+}
+
+#[test]
+fn test_clip_space_depth_range() {
+    let o = ortho(-1.0, 1.0, -1.0, 1.0, 1.0, 10.0);
+    let o01 = Ortho {
+        left: -1.0,
+        right: 1.0,
+        bottom: -1.0,
+        top: 1.0,
+        near: 1.0,
+        far: 10.0,
+    }
+    .to_matrix_with(ClipSpace::WGPU);
+    assert_relative_eq!(ndc_depth(o, 1.0), -1.0, epsilon = 1e-12);
+    assert_relative_eq!(ndc_depth(o01, 1.0), 0.0, epsilon = 1e-12);
+    assert_relative_eq!(ndc_depth(o01, 10.0), 1.0, epsilon = 1e-12);
+
+    let p = planar(Deg(45.0), 1.0, 2.0, 1.0, 10.0);
+    let p01 = PlanarFov {
+        fovy: Deg(45.0).into(),
+        aspect: 1.0,
+        height: 2.0,
+        near: 1.0,
+        far: 10.0,
+    }
+    .to_matrix_with(ClipSpace::WGPU);
+    assert_relative_eq!(ndc_depth(p, 1.0), -1.0, epsilon = 1e-12);
+    assert_relative_eq!(ndc_depth(p01, 1.0), 0.0, epsilon = 1e-12);
+    assert_relative_eq!(ndc_depth(p01, 10.0), 1.0, epsilon = 1e-12);
+
+    // Reversed-Z always maps to `[0, 1]`.
+    let persp = PerspectiveFov {
+        fovy: Deg(60.0).into(),
+        aspect: 1.5,
+        near: 0.1,
+        far: 100.0,
+    };
+    assert_eq!(
+        persp.to_matrix(PerspectiveMode::ReversedZ),
+        persp.to_matrix_with(PerspectiveMode::ReversedZ, ClipSpace::WGPU)
+    );
+}
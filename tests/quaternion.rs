@@ -469,3 +469,358 @@ mod cast {
         );
     }
 }
+
+mod exp_ln {
+    use cgmath::*;
+
+    #[test]
+    fn test_exp_ln_roundtrip() {
+        let q = Quaternion::from_axis_angle(Vector3::new(1.0, -2.0, 2.0).normalize(), Deg(100.0));
+        assert_relative_eq!(q.ln().exp(), q, epsilon = 1e-12);
+
+        let q = Quaternion::new(2.0, -1.0, 0.5, 1.5);
+        assert_relative_eq!(q.ln().exp(), q, epsilon = 1e-12);
+        assert_relative_eq!(q.exp().ln(), q, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn test_ln_axis_angle() {
+        let axis = Vector3::new(1.0, -2.0, 2.0).normalize();
+        let ln = Quaternion::from_axis_angle(axis, Deg(100.0)).ln();
+        assert_relative_eq!(ln.s, 0.0, epsilon = 1e-12);
+        assert_relative_eq!(ln.v, axis * Rad::from(Deg(50.0)).0, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn test_exp_angular_velocity() {
+        // Integrating an angular velocity of 90 degrees per second about Z for
+        // one second.
+        let omega = Vector3::new(0.0, 0.0, Rad::from(Deg(90.0f64)).0);
+        let q = Quaternion::from_sv(0.0, omega * 0.5).exp();
+        assert_relative_eq!(q, Quaternion::from_angle_z(Deg(90.0)), epsilon = 1e-12);
+    }
+
+    #[test]
+    fn test_near_identity() {
+        let q = Quaternion::<f64>::one();
+        assert_eq!(q.ln(), Quaternion::zero());
+        assert_eq!(Quaternion::<f64>::zero().exp(), q);
+
+        let tiny = Quaternion::from_axis_angle(Vector3::unit_y(), Rad(1e-10f64));
+        assert_relative_eq!(
+            tiny.ln().v,
+            Vector3::new(0.0, 0.5e-10, 0.0),
+            epsilon = 1e-24
+        );
+        assert_relative_eq!(tiny.ln().exp(), tiny, epsilon = 1e-15);
+        assert_relative_eq!(tiny.powf(0.5).v.y, 0.25e-10, epsilon = 1e-24);
+
+        let tiny = Quaternion::from_axis_angle(Vector3::unit_y(), Rad(1e-4f32));
+        assert_relative_eq!(tiny.ln().exp(), tiny, epsilon = 1e-7);
+    }
+
+    #[test]
+    fn test_ln_negative_real() {
+        let ln = Quaternion::new(-1.0f64, 0.0, 0.0, 0.0).ln();
+        assert_relative_eq!(
+            ln.exp(),
+            Quaternion::new(-1.0, 0.0, 0.0, 0.0),
+            epsilon = 1e-12
+        );
+    }
+
+    #[test]
+    fn test_powf() {
+        let axis = Vector3::new(1.0, -2.0, 2.0).normalize();
+        let q = Quaternion::from_axis_angle(axis, Deg(100.0));
+
+        assert_relative_eq!(q.powf(0.0), Quaternion::one(), epsilon = 1e-12);
+        assert_relative_eq!(q.powf(1.0), q, epsilon = 1e-12);
+        assert_relative_eq!(q.powf(2.0), q * q, epsilon = 1e-12);
+        assert_relative_eq!(
+            q.powf(0.3),
+            Quaternion::from_axis_angle(axis, Deg(30.0)),
+            epsilon = 1e-12
+        );
+        assert_relative_eq!(q.powf(-1.0), q.conjugate(), epsilon = 1e-12);
+    }
+}
+
+mod squad {
+    use cgmath::*;
+
+    fn keys() -> [Quaternion<f64>; 4] {
+        [
+            Quaternion::one(),
+            Quaternion::from_angle_x(Deg(60.0)),
+            Quaternion::from_angle_x(Deg(60.0)) * Quaternion::from_angle_y(Deg(80.0)),
+            Quaternion::from_angle_z(Deg(-30.0)),
+        ]
+    }
+
+    fn segment(keys: &[Quaternion<f64>; 4], i: usize, amount: f64) -> Quaternion<f64> {
+        let control = |i: usize| {
+            let prev = keys[if i == 0 { 0 } else { i - 1 }];
+            let next = keys[if i == 3 { 3 } else { i + 1 }];
+            keys[i].squad_control_point(prev, next)
+        };
+        keys[i].squad(control(i), control(i + 1), keys[i + 1], amount)
+    }
+
+    #[test]
+    fn test_endpoints() {
+        let keys = keys();
+        for i in 0..3 {
+            assert_relative_eq!(segment(&keys, i, 0.0), keys[i], epsilon = 1e-12);
+            assert_relative_eq!(segment(&keys, i, 1.0), keys[i + 1], epsilon = 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_continuous_velocity() {
+        // The angular velocity at the end of one segment should match that at
+        // the start of the next one.
+        let keys = keys();
+        let h = 1e-5;
+        for i in 0..2 {
+            let before = segment(&keys, i, 1.0 - h).conjugate() * keys[i + 1];
+            let after = keys[i + 1].conjugate() * segment(&keys, i + 1, h);
+            assert_relative_eq!(before.ln().v / h, after.ln().v / h, epsilon = 1e-3);
+        }
+    }
+
+    #[test]
+    fn test_matches_slerp_on_great_circle() {
+        // When evenly spaced keyframes lie on one great circle, the control
+        // points coincide with them and squad reduces to slerp.
+        let keys: Vec<_> = (0..4)
+            .map(|i| Quaternion::from_angle_z(Deg(40.0 * i as f64)))
+            .collect();
+        let b = keys[1].squad_control_point(keys[0], keys[2]);
+        let c = keys[2].squad_control_point(keys[1], keys[3]);
+        assert_relative_eq!(b, keys[1], epsilon = 1e-12);
+        assert_relative_eq!(c, keys[2], epsilon = 1e-12);
+
+        assert_relative_eq!(
+            keys[1].squad(b, c, keys[2], 0.25),
+            keys[1].slerp(keys[2], 0.25),
+            epsilon = 1e-12
+        );
+    }
+
+    #[test]
+    fn test_control_point_hemisphere() {
+        let keys = keys();
+        assert_relative_eq!(
+            keys[1].squad_control_point(-keys[0], -keys[2]),
+            keys[1].squad_control_point(keys[0], keys[2]),
+            epsilon = 1e-12
+        );
+    }
+}
+
+mod swing_twist {
+    use cgmath::*;
+
+    #[test]
+    fn test_decomposition() {
+        let q = Quaternion::from_angle_x(Deg(50.0)) * Quaternion::from_angle_y(Deg(70.0));
+        let axis = Vector3::unit_y();
+        let (swing, twist) = q.swing_twist(axis);
+
+        assert_relative_eq!(swing * twist, q, epsilon = 1e-12);
+        assert_relative_eq!(twist, Quaternion::from_angle_y(Deg(70.0)), epsilon = 1e-12);
+        assert_relative_eq!(swing.v.dot(axis), 0.0, epsilon = 1e-12);
+        assert_relative_eq!(q.twist_angle(axis), Rad::from(Deg(70.0)), epsilon = 1e-12);
+    }
+
+    #[test]
+    fn test_pure_rotations() {
+        let axis = Vector3::new(1.0, 2.0, -2.0).normalize();
+        let twist_only = Quaternion::from_axis_angle(axis, Deg(-120.0));
+        let (swing, twist) = twist_only.swing_twist(axis);
+        assert_relative_eq!(swing, Quaternion::one(), epsilon = 1e-12);
+        assert_relative_eq!(twist, twist_only, epsilon = 1e-12);
+
+        let swing_only =
+            Quaternion::from_axis_angle(Vector3::new(2.0, 0.0, 1.0).normalize(), Deg(180.0));
+        let (swing, twist) = swing_only.swing_twist(axis);
+        assert_eq!(twist, Quaternion::one());
+        assert_eq!(swing, swing_only);
+    }
+
+    #[test]
+    fn test_clamp_swing() {
+        let axis = Vector3::unit_y();
+        let q = Quaternion::from_angle_x(Deg(50.0)) * Quaternion::from_angle_y(Deg(70.0));
+
+        let clamped = q.clamp_swing(axis, Deg(20.0));
+        let (swing, twist) = clamped.swing_twist(axis);
+        assert_relative_eq!(swing, Quaternion::from_angle_x(Deg(20.0)), epsilon = 1e-12);
+        assert_relative_eq!(twist, Quaternion::from_angle_y(Deg(70.0)), epsilon = 1e-12);
+
+        assert_eq!(q.clamp_swing(axis, Deg(60.0)), q);
+    }
+
+    #[test]
+    fn test_clamp_twist() {
+        let axis = Vector3::unit_y();
+        let q = Quaternion::from_angle_x(Deg(50.0)) * Quaternion::from_angle_y(Deg(70.0));
+
+        let clamped = q.clamp_twist(axis, Deg(-30.0), Deg(30.0));
+        assert_relative_eq!(
+            clamped,
+            Quaternion::from_angle_x(Deg(50.0)) * Quaternion::from_angle_y(Deg(30.0)),
+            epsilon = 1e-12
+        );
+        assert_relative_eq!(
+            q.clamp_twist(axis, Deg(80.0), Deg(90.0)).twist_angle(axis),
+            Rad::from(Deg(80.0)),
+            epsilon = 1e-12
+        );
+
+        assert_eq!(q.clamp_twist(axis, Deg(-90.0), Deg(90.0)), q);
+    }
+}
+
+mod average {
+    use cgmath::*;
+
+    #[test]
+    fn test_empty() {
+        assert_eq!(Quaternion::<f64>::average(Vec::new()), None);
+        assert_eq!(Quaternion::<f64>::nlerp_average(Vec::new()), None);
+        assert_eq!(
+            Quaternion::average(vec![(0.0, Quaternion::<f64>::one())]),
+            None
+        );
+    }
+
+    #[test]
+    fn test_two_rotations() {
+        let a = Quaternion::from_angle_z(Deg(10.0f64));
+        let b = Quaternion::from_angle_z(Deg(130.0f64));
+
+        let average = Quaternion::average(vec![(1.0, a), (1.0, b)]).unwrap();
+        assert_relative_eq!(
+            average,
+            Quaternion::from_angle_z(Deg(70.0)),
+            epsilon = 1e-12
+        );
+
+        let average = Quaternion::average(vec![(3.0, a), (3.0, -b)]).unwrap();
+        assert_relative_eq!(
+            average,
+            Quaternion::from_angle_z(Deg(70.0)),
+            epsilon = 1e-12
+        );
+
+        let nlerp = Quaternion::nlerp_average(vec![(1.0, a), (1.0, -b)]).unwrap();
+        assert_relative_eq!(nlerp, Quaternion::from_angle_z(Deg(70.0)), epsilon = 1e-12);
+    }
+
+    #[test]
+    fn test_weights() {
+        let a = Quaternion::from_angle_x(Deg(0.0f64));
+        let b = Quaternion::from_angle_x(Deg(90.0f64));
+
+        let average = Quaternion::average(vec![(1.0, a), (0.0, b)]).unwrap();
+        assert_relative_eq!(average, a, epsilon = 1e-12);
+
+        // Closed form for two quaternions, from Markley et al.
+        let (w1, w2, d) = (1.0, 3.0, a.dot(b));
+        let z = ((w1 - w2) * (w1 - w2) + 4.0 * w1 * w2 * d * d).sqrt();
+        let expected = (a * (w1 - w2 + z) + b * (2.0 * w2 * d)).normalize();
+
+        let average = Quaternion::average(vec![(w1, a), (w2, b)]).unwrap();
+        assert_relative_eq!(average, expected, epsilon = 1e-12);
+
+        let nlerp = Quaternion::nlerp_average(vec![(w1, a), (w2, b)]).unwrap();
+        assert_relative_eq!(nlerp, a.nlerp(b, 0.75), epsilon = 1e-12);
+    }
+
+    #[test]
+    fn test_sign_invariance() {
+        let rotations = [
+            Quaternion::from_angle_x(Deg(20.0f64)),
+            Quaternion::from_angle_y(Deg(-35.0f64)),
+            Quaternion::from_angle_z(Deg(50.0f64)) * Quaternion::from_angle_x(Deg(10.0)),
+            Quaternion::from_axis_angle(Vector3::new(1.0, 1.0, 1.0).normalize(), Deg(15.0)),
+        ];
+        let weights = [0.5, 2.0, 1.0, 1.5];
+
+        let average =
+            Quaternion::average(weights.iter().cloned().zip(rotations.iter().cloned())).unwrap();
+        let flipped = Quaternion::average(
+            weights
+                .iter()
+                .cloned()
+                .zip(
+                    rotations
+                        .iter()
+                        .enumerate()
+                        .map(|(i, &q)| if i % 2 == 1 { -q } else { q }),
+                ),
+        )
+        .unwrap();
+        assert_relative_eq!(average, flipped, epsilon = 1e-12);
+        assert_relative_eq!(average.magnitude(), 1.0, epsilon = 1e-12);
+
+        let nlerp =
+            Quaternion::nlerp_average(weights.iter().cloned().zip(rotations.iter().cloned()))
+                .unwrap();
+        assert_relative_eq!(average, nlerp, epsilon = 1e-2);
+    }
+
+    #[test]
+    fn test_spread_out() {
+        // Half turns around each axis are equally far from the identity and
+        // from each other; the average of the identity weighted above them is
+        // the identity.
+        let items = vec![
+            (2.0f64, Quaternion::one()),
+            (1.0, Quaternion::from_angle_x(Deg(180.0))),
+            (1.0, Quaternion::from_angle_y(Deg(180.0))),
+            (1.0, Quaternion::from_angle_z(Deg(180.0))),
+        ];
+        assert_relative_eq!(
+            Quaternion::average(items).unwrap(),
+            Quaternion::one(),
+            epsilon = 1e-12
+        );
+    }
+}
+
+mod renormalize {
+    use cgmath::*;
+
+    #[test]
+    fn test_within_tolerance() {
+        let q = Quaternion::new(1.0, 2.0, 3.0, 4.0).normalize() * (1.0 + 1e-9);
+        assert_eq!(q.renormalize(1e-6), q);
+    }
+
+    #[test]
+    fn test_small_drift() {
+        let q = Quaternion::from_axis_angle(Vector3::new(0.0, 0.6, 0.8), Deg(50.0)) * (1.0 + 1e-4);
+        let r = q.renormalize(1e-6);
+        assert_relative_eq!(r.magnitude(), 1.0, epsilon = 1e-7);
+        assert_relative_eq!(r, q.normalize(), epsilon = 1e-7);
+    }
+
+    #[test]
+    fn test_large_drift() {
+        let q = Quaternion::new(3.0, 0.0, 4.0, 0.0);
+        assert_ulps_eq!(q.renormalize(1e-6), Quaternion::new(0.6, 0.0, 0.8, 0.0));
+    }
+
+    #[test]
+    fn test_long_running() {
+        let step = Quaternion::from_angle_y(Deg(0.37)) * (1.0 + 1e-7);
+        let mut q = Quaternion::<f64>::one();
+        for _ in 0..100_000 {
+            q = (q * step).renormalize(1e-6);
+            assert!((q.magnitude2() - 1.0).abs() <= 1e-6);
+        }
+    }
+}
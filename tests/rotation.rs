@@ -45,3 +45,23 @@ fn test_invert_basis3() {
     let a: &Matrix3<_> = a.as_ref();
     assert!(a.is_identity());
 }
+
+#[test]
+fn test_basis3_from_matrix_orthonormalized() {
+    let a: Basis3<f64> = rotation::a3();
+    let mut m: Matrix3<f64> = a.into();
+    m.x += Vector3::new(1e-4, -2e-4, 0.0);
+    m.z *= 1.001;
+
+    let b = Basis3::from_matrix_orthonormalized(m).unwrap();
+    let mb: &Matrix3<_> = b.as_ref();
+    assert_ulps_eq!(mb.transpose() * mb, Matrix3::identity());
+    assert_relative_eq!(b, a, epsilon = 1e-3);
+
+    let mirrored = Matrix3::from_diagonal(Vector3::new(-1.0, 1.0, 1.0));
+    assert_eq!(Basis3::from_matrix_orthonormalized(mirrored), None);
+    assert_eq!(
+        Basis3::from_matrix_orthonormalized(Matrix3::<f64>::zero()),
+        None
+    );
+}
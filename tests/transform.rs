@@ -164,3 +164,307 @@ fn test_serialize() {
 
     assert_ulps_eq!(&t, &deserialized);
 }
+
+#[test]
+fn test_trs_matches_matrix() {
+    let t = Trs {
+        scale: Vector3::new(1.0, 2.0, 3.0),
+        rot: Quaternion::from_axis_angle(Vector3::new(1.0, 1.0, 0.0).normalize(), Deg(30.0)),
+        disp: Vector3::new(6.0, -7.0, 8.0),
+    };
+    let m = Matrix4::from(t);
+    let p = Point3::new(1.0, -2.0, 0.5);
+    let v = Vector3::new(-3.0, 0.25, 1.0);
+
+    assert_relative_eq!(t.transform_point(p), m.transform_point(p), epsilon = 1e-12);
+    assert_relative_eq!(
+        t.transform_vector(v),
+        m.transform_vector(v),
+        epsilon = 1e-12
+    );
+}
+
+#[test]
+fn test_trs_concat() {
+    let t = Trs {
+        scale: Vector3::new(1.0, 2.0, 3.0),
+        rot: Quaternion::from_axis_angle(Vector3::new(1.0, 1.0, 0.0).normalize(), Deg(30.0)),
+        disp: Vector3::new(6.0, -7.0, 8.0),
+    };
+    let uniform = Trs {
+        scale: Vector3::new(2.0, 2.0, 2.0),
+        rot: Quaternion::from_angle_z(Deg(45.0)),
+        disp: Vector3::new(1.0, 0.0, -1.0),
+    };
+    let unrotated = Trs {
+        scale: Vector3::new(0.5, 4.0, 1.0),
+        rot: Quaternion::one(),
+        disp: Vector3::new(0.0, 3.0, 2.0),
+    };
+
+    // Exact when the scale of the left transform is uniform, or the rotation
+    // of the right transform is the identity.
+    assert_relative_eq!(
+        Matrix4::from(uniform * t),
+        Matrix4::from(uniform) * Matrix4::from(t),
+        epsilon = 1e-12
+    );
+    assert_relative_eq!(
+        Matrix4::from(t * unrotated),
+        Matrix4::from(t) * Matrix4::from(unrotated),
+        epsilon = 1e-12
+    );
+
+    // Otherwise the shear is discarded, but the displacement is still exact.
+    let p = Point3::origin();
+    assert_relative_eq!(
+        (t * uniform).transform_point(p),
+        t.transform_point(uniform.transform_point(p)),
+        epsilon = 1e-12
+    );
+
+    assert_ulps_eq!(t * Trs::one(), t);
+    assert_ulps_eq!(Trs::one() * t, t);
+}
+
+#[test]
+fn test_trs_invert() {
+    let t = Trs {
+        scale: Vector3::new(1.0, 2.0, 3.0),
+        rot: Quaternion::from_axis_angle(Vector3::new(1.0, 1.0, 0.0).normalize(), Deg(30.0)),
+        disp: Vector3::new(6.0, -7.0, 8.0),
+    };
+    let v = Vector3::new(1.0, 2.0, 3.0);
+
+    // A non-uniform scale followed by a rotation has no TRS inverse.
+    assert_eq!(t.inverse_transform(), None);
+    let vt = t.inverse_transform_vector(v).unwrap();
+    assert_relative_eq!(t.transform_vector(vt), v, epsilon = 1e-12);
+
+    let uniform = Trs {
+        scale: Vector3::new(1.5, 1.5, 1.5),
+        ..t
+    };
+    let unrotated = Trs {
+        rot: Quaternion::one(),
+        ..t
+    };
+    for t in &[uniform, unrotated] {
+        let ti = t.inverse_transform().unwrap();
+        assert_relative_eq!(*t * ti, Trs::one(), epsilon = 1e-12);
+        assert_relative_eq!(ti * *t, Trs::one(), epsilon = 1e-12);
+    }
+
+    let degenerate = Trs {
+        scale: Vector3::new(1.0, 0.0, 1.0),
+        ..t
+    };
+    assert_eq!(degenerate.inverse_transform(), None);
+    assert_eq!(degenerate.inverse_transform_vector(v), None);
+}
+
+#[test]
+fn test_trs_invert_small_scale() {
+    let t = Trs {
+        scale: Vector3::new(1e-8f32, 1e-8, 1e-8),
+        rot: Quaternion::from_angle_z(Deg(30.0)),
+        disp: Vector3::new(1e-8, -2e-8, 3e-8),
+    };
+    let ti = t.inverse_transform().unwrap();
+    assert_relative_eq!(ti.scale, Vector3::new(1e8, 1e8, 1e8));
+    assert_relative_eq!(
+        ti.transform_point(t.transform_point(Point3::new(1.0, 2.0, 3.0))),
+        Point3::new(1.0, 2.0, 3.0),
+        max_relative = 1e-5
+    );
+
+    // Small scales that differ relative to each other are still non-uniform.
+    let non_uniform = Trs {
+        scale: Vector3::new(1e-8, 2e-8, 3e-8),
+        ..t
+    };
+    assert_eq!(non_uniform.inverse_transform(), None);
+}
+
+#[test]
+fn test_trs_from_matrix4() {
+    let t = Trs {
+        scale: Vector3::new(1.0, 2.0, 3.0),
+        rot: Quaternion::from_axis_angle(Vector3::new(1.0, 1.0, 0.0).normalize(), Deg(30.0)),
+        disp: Vector3::new(6.0, -7.0, 8.0),
+    };
+    assert_relative_eq!(
+        Trs::from_matrix4(Matrix4::from(t)).unwrap(),
+        t,
+        epsilon = 1e-12
+    );
+
+    let mirrored = Trs {
+        scale: Vector3::new(-1.0, 2.0, 3.0),
+        ..t
+    };
+    let m = Matrix4::from(mirrored);
+    let from_m = Trs::from_matrix4(m).unwrap();
+    assert_relative_eq!(from_m, mirrored, epsilon = 1e-12);
+
+    // The shear is dropped, and the scale along Y is the part of the Y
+    // column orthogonal to X rather than the length of the column.
+    let sheared = Matrix4::from(Matrix3::new(2.0, 0.0, 0.0, 3.0, 3.0, 0.0, 0.0, 0.0, 1.0));
+    let from_sheared = Trs::from_matrix4(sheared).unwrap();
+    assert_relative_eq!(
+        from_sheared.scale,
+        Vector3::new(2.0, 3.0, 1.0),
+        epsilon = 1e-12
+    );
+    assert_relative_eq!(from_sheared.rot, Quaternion::one(), epsilon = 1e-12);
+    assert_relative_eq!(
+        from_sheared.scale,
+        sheared.decompose().unwrap().scale,
+        epsilon = 1e-12
+    );
+
+    assert_eq!(
+        Trs::from_matrix4(Matrix4::from_nonuniform_scale(1.0, 0.0, 1.0)),
+        None
+    );
+    assert_eq!(
+        Trs::from_matrix4(perspective(Deg(60.0), 1.0, 0.1, 10.0)),
+        None
+    );
+}
+
+#[test]
+fn test_trs_from_decomposed() {
+    let d = Decomposed {
+        scale: 1.5f64,
+        rot: Quaternion::new(0.5f64, 0.5, 0.5, 0.5),
+        disp: Vector3::new(6.0f64, -7.0, 8.0),
+    };
+    let t = Trs::from(d);
+    assert_eq!(t.scale, Vector3::new(1.5, 1.5, 1.5));
+    assert_ulps_eq!(Matrix4::from(t), Matrix4::from(d));
+}
+
+#[test]
+fn test_trs_2d() {
+    let t = Trs {
+        scale: Vector2::new(2.0f64, 3.0),
+        rot: Basis2::from_angle(Deg(90.0)),
+        disp: Vector2::new(1.0, 1.0),
+    };
+    let p = Point2::new(1.0, 1.0);
+    assert_relative_eq!(
+        t.transform_point(p),
+        Point2::new(-2.0, 3.0),
+        epsilon = 1e-12
+    );
+    assert_relative_eq!(
+        Matrix3::from(t).transform_point(p),
+        Point2::new(-2.0, 3.0),
+        epsilon = 1e-12
+    );
+}
+
+#[test]
+fn test_fit_rigid() {
+    let t = Decomposed {
+        scale: 1.0,
+        rot: Quaternion::from_axis_angle(Vector3::new(1.0, 2.0, -1.0).normalize(), Deg(130.0)),
+        disp: Vector3::new(4.0, -5.0, 6.0),
+    };
+    let source = [
+        Point3::new(0.0, 0.0, 0.0),
+        Point3::new(1.0, 0.0, 0.0),
+        Point3::new(0.0, 2.0, 0.0),
+        Point3::new(0.0, 0.0, 3.0),
+        Point3::new(1.0, -1.0, 2.0),
+    ];
+    let target: Vec<_> = source.iter().map(|&p| t.transform_point(p)).collect();
+    let (fit, error) = Decomposed::fit_rigid(&source, &target).unwrap();
+    assert_relative_eq!(Matrix4::from(fit), Matrix4::from(t), epsilon = 1e-10);
+    assert!(error < 1e-10);
+
+    // A mirrored target is fitted with a rotation, leaving a residual.
+    let mirrored: Vec<_> = target.iter().map(|p| Point3::new(-p.x, p.y, p.z)).collect();
+    let (fit, error) = Decomposed::fit_rigid(&source, &mirrored).unwrap();
+    assert_relative_eq!(fit.rot.magnitude(), 1.0, epsilon = 1e-12);
+    assert_relative_eq!(Matrix3::from(fit.rot).determinant(), 1.0, epsilon = 1e-12);
+    assert!(error > 0.1);
+
+    assert_eq!(Decomposed::fit_rigid(&source, &target[1..]), None);
+    assert_eq!(Decomposed::<Vector3<f64>, _>::fit_rigid(&[], &[]), None);
+}
+
+#[test]
+fn test_fit_similarity() {
+    let t = Decomposed {
+        scale: 2.5,
+        rot: Quaternion::from_axis_angle(Vector3::new(0.0, 1.0, 1.0).normalize(), Deg(-70.0)),
+        disp: Vector3::new(-1.0, 0.5, 2.0),
+    };
+    let source = [
+        Point3::new(0.0, 0.0, 0.0),
+        Point3::new(1.0, 0.0, 0.0),
+        Point3::new(0.0, 2.0, 0.0),
+        Point3::new(0.0, 0.0, 3.0),
+        Point3::new(1.0, -1.0, 2.0),
+    ];
+    let target: Vec<_> = source.iter().map(|&p| t.transform_point(p)).collect();
+    let (fit, error) = Decomposed::fit_similarity(&source, &target).unwrap();
+    assert_relative_eq!(fit.scale, 2.5, epsilon = 1e-10);
+    assert_relative_eq!(Matrix4::from(fit), Matrix4::from(t), epsilon = 1e-10);
+    assert!(error < 1e-10);
+
+    // Coincident source points can only be moved onto the target centroid,
+    // and have no spread to derive a scale from.
+    let offsets = [Vector3::new(0.1, 0.0, 0.0), Vector3::new(-0.1, 0.0, 0.0)];
+    let points = [Point3::new(1.0, 1.0, 1.0); 2];
+    let target: Vec<_> = points
+        .iter()
+        .zip(offsets.iter())
+        .map(|(&p, &o)| p + o)
+        .collect();
+    let (fit, error) = Decomposed::fit_rigid(&points, &target).unwrap();
+    assert_relative_eq!(
+        fit.transform_point(points[0]),
+        Point3::new(1.0, 1.0, 1.0),
+        epsilon = 1e-12
+    );
+    assert_relative_eq!(error, 0.1, epsilon = 1e-12);
+    assert_eq!(Decomposed::fit_similarity(&points, &target), None);
+
+    // The check for coincident points does not depend on their scale.
+    let tiny: Vec<_> = source.iter().map(|&p| p * 1e-12).collect();
+    let tiny_t = Decomposed {
+        disp: t.disp * 1e-12,
+        ..t
+    };
+    let tiny_target: Vec<_> = tiny.iter().map(|&p| tiny_t.transform_point(p)).collect();
+    let (fit, _) = Decomposed::fit_similarity(&tiny, &tiny_target).unwrap();
+    assert_relative_eq!(fit.scale, 2.5, epsilon = 1e-10);
+    assert_relative_eq!(fit.rot, t.rot, epsilon = 1e-10);
+
+    // Points that only differ by rounding error count as coincident.
+    let far = [
+        Point3::new(1e6, -1e6, 1e6),
+        Point3::new(1e6 + 2e-10, -1e6, 1e6),
+        Point3::new(1e6, -1e6, 1e6 - 2e-10),
+    ];
+    assert_eq!(Decomposed::fit_similarity(&far, &tiny_target[..3]), None);
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_serialize_trs() {
+    let t = Trs {
+        scale: Vector3::new(1.0, 2.0, 3.0),
+        rot: Quaternion::from_axis_angle(Vector3::new(1.0, 1.0, 0.0).normalize(), Deg(30.0)),
+        disp: Vector3::new(6.0, -7.0, 8.0),
+    };
+
+    let serialized = serde_json::to_string(&t).unwrap();
+    let deserialized: Trs<Vector3<f64>, Quaternion<f64>> =
+        serde_json::from_str(&serialized).unwrap();
+
+    assert_ulps_eq!(&t, &deserialized);
+}
@@ -0,0 +1,100 @@
+// Copyright 2013-2014 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+extern crate approx;
+extern crate cgmath;
+
+use cgmath::*;
+
+#[test]
+fn test_angle() {
+    for &degrees in [0.0f64, 30.0, 90.0, 179.0, -120.0].iter() {
+        let c = UnitComplex::from_angle(Deg(degrees));
+        assert_relative_eq!(Deg::from(c.angle()), Deg(degrees), epsilon = 1e-12);
+    }
+}
+
+#[test]
+fn test_matches_basis2() {
+    let c = UnitComplex::from_angle(Deg(35.0f64));
+    let basis = Basis2::from_angle(Deg(35.0f64));
+    let v = Vector2::new(2.0, -1.0);
+
+    assert_relative_eq!(c.rotate_vector(v), basis.rotate_vector(v), epsilon = 1e-12);
+    assert_relative_eq!(Basis2::from(c), basis, epsilon = 1e-12);
+    assert_relative_eq!(UnitComplex::from(basis), c, epsilon = 1e-12);
+    assert_relative_eq!(
+        Matrix2::from(c),
+        Matrix2::from_angle(Deg(35.0)),
+        epsilon = 1e-12
+    );
+    assert_relative_eq!(
+        Matrix3::from(c),
+        Matrix3::from(Matrix2::from_angle(Deg(35.0))),
+        epsilon = 1e-12
+    );
+}
+
+#[test]
+fn test_composition() {
+    let a = UnitComplex::from_angle(Deg(50.0f64));
+    let b = UnitComplex::from_angle(Deg(-80.0f64));
+
+    assert_relative_eq!(a * b, UnitComplex::from_angle(Deg(-30.0)), epsilon = 1e-12);
+    assert_relative_eq!(a * a.invert(), UnitComplex::one(), epsilon = 1e-12);
+
+    let product: UnitComplex<f64> = [a, b, a].iter().product();
+    assert_relative_eq!(product, UnitComplex::from_angle(Deg(20.0)), epsilon = 1e-12);
+}
+
+#[test]
+fn test_slerp() {
+    let a = UnitComplex::from_angle(Deg(170.0f64));
+    let b = UnitComplex::from_angle(Deg(-150.0f64));
+
+    // The shortest path crosses the negative x axis.
+    assert_relative_eq!(a.slerp(b, 0.0), a, epsilon = 1e-12);
+    assert_relative_eq!(
+        a.slerp(b, 0.25),
+        UnitComplex::from_angle(Deg(180.0)),
+        epsilon = 1e-12
+    );
+    assert_relative_eq!(a.slerp(b, 1.0), b, epsilon = 1e-12);
+}
+
+#[test]
+fn test_between_vectors() {
+    let a = Vector2::new(1.0f64, 1.0).normalize();
+    let b = Vector2::new(-1.0f64, 0.0);
+    let c = UnitComplex::between_vectors(a, b);
+
+    assert_relative_eq!(c.angle(), Rad::from(Deg(135.0)), epsilon = 1e-12);
+    assert_relative_eq!(c.rotate_vector(a), b, epsilon = 1e-12);
+
+    let look = UnitComplex::look_at(Vector2::new(0.0, 2.0), Vector2::unit_y());
+    assert_relative_eq!(look, UnitComplex::from_angle(Deg(90.0)), epsilon = 1e-12);
+}
+
+#[test]
+fn test_normalize() {
+    let mut c = UnitComplex::from_angle(Deg(1.0f64));
+    for _ in 0..1000 {
+        c = c * UnitComplex::new(1.0 + 1e-6, 0.0);
+    }
+    assert!((c.re * c.re + c.im * c.im - 1.0).abs() > 1e-3);
+
+    let c = c.normalize();
+    assert_relative_eq!(c, UnitComplex::from_angle(Deg(1.0)), epsilon = 1e-12);
+}